@@ -0,0 +1,132 @@
+use super::{AssertionFailure, Spec};
+
+use approx_crate::{AbsDiffEq, RelativeEq};
+
+use std::borrow::Borrow;
+use std::fmt::Debug;
+
+pub trait ApproxAssertions<T: AbsDiffEq> {
+    fn is_approx_close_to<E: Borrow<T>>(&mut self, expected: E, epsilon: T::Epsilon);
+    fn is_relatively_close_to<E: Borrow<T>>(&mut self,
+                                            expected: E,
+                                            epsilon: T::Epsilon,
+                                            max_relative: T::Epsilon)
+        where T: RelativeEq;
+}
+
+impl<'s, T> ApproxAssertions<T> for Spec<'s, T>
+    where T: AbsDiffEq + Debug,
+          T::Epsilon: Debug
+{
+    /// Asserts that the subject is close to the expected value using the `AbsDiffEq`
+    /// implementation of the `approx` crate, with the provided epsilon as the maximum absolute
+    /// difference.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&1.0f64).is_approx_close_to(1.05, 0.1);
+    /// ```
+    fn is_approx_close_to<E: Borrow<T>>(&mut self, expected: E, epsilon: T::Epsilon) {
+        let borrowed_expected = expected.borrow();
+        let expected_message = format!("value close to <{:?}> (epsilon of <{:?}>)",
+                                       borrowed_expected,
+                                       epsilon);
+
+        if !self.subject.abs_diff_eq(borrowed_expected, epsilon) {
+            AssertionFailure::from_spec(self)
+                .with_expected(expected_message)
+                .with_actual(format!("<{:?}>", self.subject))
+                .fail();
+        }
+    }
+
+    /// Asserts that the subject is close to the expected value using the `RelativeEq`
+    /// implementation of the `approx` crate, with the provided epsilon as the maximum absolute
+    /// difference and `max_relative` as the maximum difference relative to the larger value.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&1000.0f64).is_relatively_close_to(1001.0, 0.0, 0.01);
+    /// ```
+    fn is_relatively_close_to<E: Borrow<T>>(&mut self,
+                                            expected: E,
+                                            epsilon: T::Epsilon,
+                                            max_relative: T::Epsilon)
+        where T: RelativeEq
+    {
+        let borrowed_expected = expected.borrow();
+        let expected_message = format!("value relatively close to <{:?}> (epsilon of <{:?}>, \
+                                        max relative of <{:?}>)",
+                                       borrowed_expected,
+                                       epsilon,
+                                       max_relative);
+
+        if !self.subject.relative_eq(borrowed_expected, epsilon, max_relative) {
+            AssertionFailure::from_spec(self)
+                .with_expected(expected_message)
+                .with_actual(format!("<{:?}>", self.subject))
+                .fail();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+
+    use approx_crate::AbsDiffEq;
+
+    #[derive(Debug, PartialEq)]
+    struct Position {
+        x: f64,
+        y: f64,
+    }
+
+    impl AbsDiffEq for Position {
+        type Epsilon = f64;
+
+        fn default_epsilon() -> f64 {
+            f64::EPSILON
+        }
+
+        fn abs_diff_eq(&self, other: &Position, epsilon: f64) -> bool {
+            self.x.abs_diff_eq(&other.x, epsilon) && self.y.abs_diff_eq(&other.y, epsilon)
+        }
+    }
+
+    #[test]
+    fn should_not_panic_if_value_is_approx_close_to_expected() {
+        assert_that(&1.0f64).is_approx_close_to(1.05, 0.1);
+        assert_that(&Position { x: 1.0, y: 2.0 })
+            .is_approx_close_to(Position { x: 1.01, y: 1.99 }, 0.05);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value close to <1.5> (epsilon of <0.1>)\
+                   \n\t but was: <1.0>")]
+    fn should_panic_if_value_is_not_approx_close_to_expected() {
+        assert_that(&1.0f64).is_approx_close_to(1.5, 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [cursor]\
+                   \n\texpected: value close to <Position { x: 1.0, y: 3.0 }> (epsilon of \
+                   <0.05>)\n\t but was: <Position { x: 1.0, y: 2.0 }>")]
+    fn should_panic_if_custom_value_is_not_approx_close_to_expected() {
+        assert_that(&Position { x: 1.0, y: 2.0 })
+            .named("cursor")
+            .is_approx_close_to(Position { x: 1.0, y: 3.0 }, 0.05);
+    }
+
+    #[test]
+    fn should_not_panic_if_value_is_relatively_close_to_expected() {
+        assert_that(&1000.0f64).is_relatively_close_to(1001.0, 0.0, 0.01);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value relatively close to <1100.0> (epsilon of \
+                   <0.0>, max relative of <0.01>)\n\t but was: <1000.0>")]
+    fn should_panic_if_value_is_not_relatively_close_to_expected() {
+        assert_that(&1000.0f64).is_relatively_close_to(1100.0, 0.0, 0.01);
+    }
+
+}
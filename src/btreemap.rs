@@ -0,0 +1,64 @@
+use super::{AssertionFailure, Spec};
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+pub trait BTreeMapAssertions {
+    fn has_keys_in_ascending_order(&mut self);
+}
+
+impl<'s, K, V> BTreeMapAssertions for Spec<'s, BTreeMap<K, V>>
+    where K: Ord + Debug
+{
+    /// Asserts that the keys of the subject btreemap are iterated in ascending order. A
+    /// `BTreeMap` always keeps its keys ordered, so this documents the ordering that a test relies
+    /// on rather than checking something that can normally go wrong.
+    ///
+    /// ```rust,ignore
+    /// let mut test_map = BTreeMap::new();
+    /// test_map.insert(2, "two");
+    /// test_map.insert(1, "one");
+    ///
+    /// assert_that(&test_map).has_keys_in_ascending_order();
+    /// ```
+    fn has_keys_in_ascending_order(&mut self) {
+        let keys: Vec<&K> = self.subject.keys().collect();
+        let unordered_pair = keys.windows(2).enumerate().find(|&(_, pair)| pair[0] >= pair[1]);
+
+        if let Some((index, pair)) = unordered_pair {
+            AssertionFailure::from_spec(self)
+                .with_expected("btreemap with keys in ascending order".to_string())
+                .with_actual(format!("key <{:?}> at index <{}> before <{:?}>",
+                                     pair[0],
+                                     index,
+                                     pair[1]))
+                .fail();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn should_not_panic_if_btreemap_keys_are_in_ascending_order() {
+        let mut test_map = BTreeMap::new();
+        test_map.insert("hey", 3);
+        test_map.insert("hello", 1);
+        test_map.insert("hi", 2);
+
+        assert_that(&test_map).has_keys_in_ascending_order();
+    }
+
+    #[test]
+    fn should_not_panic_if_btreemap_is_empty() {
+        let test_map: BTreeMap<i32, i32> = BTreeMap::new();
+
+        assert_that(&test_map).has_keys_in_ascending_order();
+    }
+
+}
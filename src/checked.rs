@@ -0,0 +1,245 @@
+//! Non-panicking assertions.
+//!
+//! Outside of tests (such as in build scripts or validators) a panic is rarely what you want when
+//! a value doesn't hold up. `assert_that_result` wraps a subject so that any number of assertions
+//! can be checked against it, with the failures collected into a `Result` rather than panicking.
+//!
+//! ```rust,ignore
+//! #[derive(Debug)]
+//! struct Config {
+//!     name: String,
+//!     port: u16,
+//! }
+//!
+//! fn validate(config: &Config) -> Result<(), String> {
+//!     assert_that_result(config)
+//!         .check(|spec| spec.map(|config| &config.name).is_not_equal_to("".to_owned()))
+//!         .check(|spec| spec.map(|config| &config.port).is_greater_than(1024))
+//!         .into_result()
+//! }
+//! ```
+//!
+//! NOTE: Failures are captured by unwinding, so this will not work when compiled with
+//! `panic = "abort"`.
+
+use super::{assert_that, Spec};
+
+use std::any::Any;
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+thread_local!(static CAPTURING_FAILURES: Cell<bool> = const { Cell::new(false) });
+
+static INSTALL_PANIC_HOOK: Once = Once::new();
+
+/// A subject whose assertions are collected rather than causing a panic.
+///
+/// This is created by the `assert_that_result` function.
+#[derive(Debug)]
+pub struct CheckedSpec<'s, S: 's> {
+    subject: &'s S,
+    failures: Vec<String>,
+    max_failures: Option<usize>,
+    suppressed_failures: usize,
+}
+
+/// Wraps a subject in a `CheckedSpec`, allowing assertions to be made against it without
+/// panicking.
+///
+/// The subject must be a reference.
+pub fn assert_that_result<'s, S>(subject: &'s S) -> CheckedSpec<'s, S> {
+    CheckedSpec {
+        subject,
+        failures: vec![],
+        max_failures: None,
+        suppressed_failures: 0,
+    }
+}
+
+impl<'s, S> CheckedSpec<'s, S> {
+    /// Limits the number of failure messages which are collected. Any further failures are still
+    /// counted, and are noted as suppressed at the end of the error.
+    ///
+    /// ```rust,ignore
+    /// assert_that_result(&values).with_max_failures(10);
+    /// ```
+    pub fn with_max_failures(self, limit: usize) -> Self {
+        CheckedSpec { max_failures: Some(limit), ..self }
+    }
+
+    /// Runs the provided assertions against a `Spec` for the subject, recording the failure
+    /// message if they fail.
+    ///
+    /// ```rust,ignore
+    /// assert_that_result(&1).check(|mut spec| spec.is_equal_to(&1));
+    /// ```
+    pub fn check<F>(self, assertions: F) -> Self
+        where F: FnOnce(Spec<'s, S>)
+    {
+        let mut checked = self;
+        let subject = checked.subject;
+
+        if let Some(failure) = capture_failure(|| assertions(assert_that(subject))) {
+            match checked.max_failures {
+                Some(limit) if checked.failures.len() >= limit => checked.suppressed_failures += 1,
+                _ => checked.failures.push(failure),
+            }
+        }
+
+        checked
+    }
+
+    /// Returns `Ok` if every check passed, otherwise an `Err` containing the failure messages of
+    /// each failed check in the order they were made.
+    pub fn into_result(self) -> Result<(), String> {
+        if self.failures.is_empty() && self.suppressed_failures == 0 {
+            return Ok(());
+        }
+
+        let mut message = self.failures.concat();
+
+        match self.suppressed_failures {
+            0 => {}
+            1 => message.push_str("\n\t1 more failure suppressed\n"),
+            suppressed => {
+                message.push_str(&format!("\n\t{} more failures suppressed\n", suppressed))
+            }
+        }
+
+        Err(message)
+    }
+}
+
+pub(crate) fn capture_failure<F: FnOnce()>(assertions: F) -> Option<String> {
+    // Failures are still raised as panics, so stop the panic hook from reporting them while they
+    // are being captured on this thread.
+    INSTALL_PANIC_HOOK.call_once(|| {
+        let previous_hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if !CAPTURING_FAILURES.with(|capturing| capturing.get()) {
+                previous_hook(info);
+            }
+        }));
+    });
+
+    CAPTURING_FAILURES.with(|capturing| capturing.set(true));
+    let outcome = panic::catch_unwind(AssertUnwindSafe(assertions));
+    CAPTURING_FAILURES.with(|capturing| capturing.set(false));
+
+    outcome.err().map(build_failure_message)
+}
+
+fn build_failure_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => {
+            match payload.downcast::<&'static str>() {
+                Ok(message) => message.to_string(),
+                Err(_) => "assertion failed with an unknown panic".to_string(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+
+    #[derive(Debug)]
+    struct Config {
+        name: String,
+        port: u16,
+    }
+
+    fn validate(config: &Config) -> Result<(), String> {
+        assert_that_result(config)
+            .check(|spec| spec.map(|config| &config.name).is_not_equal_to("".to_owned()))
+            .check(|spec| spec.map(|config| &config.port).is_greater_than(1024))
+            .into_result()
+    }
+
+    #[test]
+    fn should_return_ok_if_all_checks_pass() {
+        let config = Config {
+            name: "server".to_owned(),
+            port: 8080,
+        };
+
+        assert_that(&validate(&config)).is_ok();
+    }
+
+    #[test]
+    fn should_return_err_if_a_check_fails() {
+        let config = Config {
+            name: "server".to_owned(),
+            port: 80,
+        };
+
+        assert_that(&validate(&config))
+            .is_err()
+            .is_equal_to("\n\texpected: value greater than <1024>\n\t but was: <80>\n".to_owned());
+    }
+
+    #[test]
+    fn should_aggregate_failures_from_every_failed_check() {
+        let config = Config {
+            name: "".to_owned(),
+            port: 80,
+        };
+
+        assert_that(&validate(&config))
+            .is_err()
+            .is_equal_to("\n\texpected: <\"\"> to not equal <\"\">\n\t but was: equal\n\
+                          \n\texpected: value greater than <1024>\n\t but was: <80>\n"
+                .to_owned());
+    }
+
+    #[test]
+    fn should_suppress_failures_beyond_the_maximum() {
+        let values: Vec<u16> = (1..6).collect();
+
+        let result = values.iter()
+            .fold(assert_that_result(&values).with_max_failures(2), |checked, value| {
+                checked.check(move |mut spec| spec.contains(value * 10))
+            })
+            .into_result();
+
+        assert_that(&result)
+            .is_err()
+            .is_equal_to("\n\texpected: iterator to contain <10>\n\t but was: <[1, 2, 3, 4, 5]>\n\
+                          \n\texpected: iterator to contain <20>\n\t but was: <[1, 2, 3, 4, 5]>\n\
+                          \n\t3 more failures suppressed\n"
+                .to_owned());
+    }
+
+    #[test]
+    fn should_not_note_suppressed_failures_within_the_maximum() {
+        let config = Config {
+            name: "server".to_owned(),
+            port: 80,
+        };
+
+        let result = assert_that_result(&config)
+            .with_max_failures(1)
+            .check(|spec| spec.map(|config| &config.port).is_greater_than(1024))
+            .into_result();
+
+        assert_that(&result)
+            .is_err()
+            .is_equal_to("\n\texpected: value greater than <1024>\n\t but was: <80>\n".to_owned());
+    }
+
+    #[test]
+    fn should_return_err_if_every_failure_is_suppressed() {
+        let result = assert_that_result(&1)
+            .with_max_failures(0)
+            .check(|mut spec| spec.is_equal_to(2))
+            .into_result();
+
+        assert_that(&result).is_err().is_equal_to("\n\t1 more failure suppressed\n".to_owned());
+    }
+
+}
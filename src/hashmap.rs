@@ -1,12 +1,16 @@
 use super::{AssertionFailure, Spec};
+use super::numeric::{self, Abs};
 
 use std::borrow::Borrow;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::iter;
+use std::ops::Sub;
 
 pub trait HashMapAssertions<'s, K: Hash + Eq, V: PartialEq> {
     fn has_length(&mut self, expected: usize);
+    fn has_length_matching<F: Fn(usize) -> bool>(&mut self, predicate: F);
     fn is_empty(&mut self);
     fn contains_key<E: Borrow<K>>(&mut self, expected_key: E) -> Spec<'s, V>;
     fn does_not_contain_key<E: Borrow<K>>(&mut self, expected_key: E);
@@ -14,6 +18,33 @@ pub trait HashMapAssertions<'s, K: Hash + Eq, V: PartialEq> {
     fn does_not_contain_entry<E: Borrow<K>, F: Borrow<V>>(&mut self,
                                                           expected_key: E,
                                                           expected_value: F);
+    fn merged_with_and_assert<F>(&mut self, other: &HashMap<K, V>, assertions: F)
+        where K: Clone,
+              V: Clone,
+              F: FnOnce(Spec<HashMap<K, V>>);
+    fn has_exactly_keys<EK: Borrow<K>>(&mut self, expected_keys: &[EK]);
+    fn values_are_close_to<E: Borrow<HashMap<K, V>>>(&mut self, expected: E, tolerance: V)
+        where V: Sub<Output = V> + PartialOrd + Abs + Copy;
+    fn each_entry_matching<F: Fn(&K, &V) -> bool>(&mut self, predicate: F);
+    fn entry_count_satisfying_and_assert<F, G>(&mut self, predicate: F, assertions: G)
+        where F: Fn(&K, &V) -> bool,
+              G: FnOnce(Spec<usize>);
+    fn merged_all_values_equal(&mut self, other: &HashMap<K, V>);
+    fn is_superset_map_of<E: Borrow<HashMap<K, V>>>(&mut self, expected: E);
+    fn transform_values_and_assert<U, F, G>(&mut self, f: F, assertions: G)
+        where K: Clone,
+              U: Debug,
+              F: Fn(&V) -> U,
+              G: FnOnce(Spec<HashMap<K, U>>);
+    fn all_values_are_unique(&mut self) where V: Hash + Eq;
+}
+
+pub trait HashMapOptionAssertions {
+    fn has_no_none_values(&mut self);
+}
+
+pub trait HashMapCountAssertions<K> {
+    fn most_frequent_key_is<EK: Borrow<K>>(&mut self, expected_key: EK);
 }
 
 impl<'s, K, V> HashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
@@ -41,6 +72,27 @@ impl<'s, K, V> HashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
         }
     }
 
+    /// Asserts that the length of the subject hashmap satisfies the provided predicate. The subject
+    /// type must be of `HashMap`.
+    ///
+    /// ```rust,ignore
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert(1, 1);
+    /// test_map.insert(2, 2);
+    ///
+    /// assert_that(&test_map).has_length_matching(|length| length % 2 == 0);
+    /// ```
+    fn has_length_matching<F: Fn(usize) -> bool>(&mut self, predicate: F) {
+        let length = self.subject.len();
+
+        if !predicate(length) {
+            AssertionFailure::from_spec(self)
+                .with_expected("hashmap to have length matching predicate".to_string())
+                .with_actual(format!("<{}>", length))
+                .fail();
+        }
+    }
+
     /// Asserts that the subject hashmap is empty. The subject type must be of `HashMap`.
     ///
     /// ```rust,ignore
@@ -184,6 +236,425 @@ impl<'s, K, V> HashMapAssertions<'s, K, V> for Spec<'s, HashMap<K, V>>
                 .fail();
         }
     }
+
+    /// Merges the subject hashmap with the provided hashmap, then passes a `Spec` over the merged
+    /// hashmap to the provided assertions. Values from the subject take precedence when both
+    /// hashmaps contain the same key.
+    ///
+    /// ```rust,ignore
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("hello", "hi");
+    ///
+    /// let mut other_map = HashMap::new();
+    /// other_map.insert("hey", "hi");
+    ///
+    /// assert_that(&test_map).merged_with_and_assert(&other_map, |mut spec| spec.has_length(2));
+    /// ```
+    fn merged_with_and_assert<F>(&mut self, other: &HashMap<K, V>, assertions: F)
+        where K: Clone,
+              V: Clone,
+              F: FnOnce(Spec<HashMap<K, V>>)
+    {
+        let mut merged = other.clone();
+        merged.extend(self.subject.iter().map(|(key, value)| (key.clone(), value.clone())));
+
+        assertions(Spec {
+            subject: &merged,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
+
+    /// Asserts that the subject hashmap has exactly the provided keys, in any order. Both the
+    /// expected keys which are missing and any extra keys are reported.
+    ///
+    /// ```rust,ignore
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("hello", "hi");
+    /// test_map.insert("hey", "hi");
+    ///
+    /// assert_that(&test_map).has_exactly_keys(&["hey", "hello"]);
+    /// ```
+    fn has_exactly_keys<EK: Borrow<K>>(&mut self, expected_keys: &[EK]) {
+        let subject = self.subject;
+        let borrowed_expected_keys: Vec<&K> =
+            expected_keys.iter().map(|key| key.borrow()).collect();
+
+        let missing_keys = borrowed_expected_keys.iter()
+            .cloned()
+            .filter(|key| !subject.contains_key(key));
+        let extra_keys = subject.keys().filter(|key| !borrowed_expected_keys.contains(key));
+
+        let problems = describe_key_differences(missing_keys, extra_keys);
+
+        if !problems.is_empty() {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("hashmap with exactly keys <{:?}>", borrowed_expected_keys))
+                .with_actual(problems.join(", "))
+                .fail();
+        }
+    }
+
+    /// Asserts that the subject hashmap has the same keys as the expected hashmap, and that the
+    /// value for each key is close to the expected value by the specified tolerance. The value
+    /// type must implement `Sub`, `PartialOrd`, `Abs` and `Copy`.
+    ///
+    /// ```rust,ignore
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("latency", 10.02);
+    ///
+    /// let mut expected_map = HashMap::new();
+    /// expected_map.insert("latency", 10.0);
+    ///
+    /// assert_that(&test_map).values_are_close_to(&expected_map, 0.1);
+    /// ```
+    fn values_are_close_to<E: Borrow<HashMap<K, V>>>(&mut self, expected: E, tolerance: V)
+        where V: Sub<Output = V> + PartialOrd + Abs + Copy
+    {
+        let subject = self.subject;
+        let borrowed_expected = expected.borrow();
+        let borrowed_tolerance = tolerance.abs();
+
+        let missing_keys = borrowed_expected.keys().filter(|key| !subject.contains_key(key));
+        let extra_keys = subject.keys().filter(|key| !borrowed_expected.contains_key(key));
+
+        let mut problems = describe_key_differences(missing_keys, extra_keys);
+        let mut distant_values: Vec<String> = vec![];
+
+        for (key, value) in subject {
+            if let Some(expected_value) = borrowed_expected.get(key) {
+                match numeric::difference(*value, *expected_value) {
+                    Some(difference) if difference <= borrowed_tolerance => {}
+                    Some(difference) => {
+                        distant_values.push(format!("key <{:?}> with value <{:?}> differing by \
+                                                     <{:?}>",
+                                                    key,
+                                                    value,
+                                                    difference))
+                    }
+                    None => {
+                        distant_values.push(format!("key <{:?}> with incomparable value <{:?}>",
+                                                    key,
+                                                    value))
+                    }
+                }
+            }
+        }
+
+        // Sort so that the failure message doesn't depend on the iteration order of the hashmaps.
+        distant_values.sort();
+        problems.extend(distant_values);
+
+        if problems.is_empty() {
+            return;
+        }
+
+        AssertionFailure::from_spec(self)
+            .with_expected(format!("hashmap with the expected keys and values within <{:?}> of \
+                                    them",
+                                   borrowed_tolerance))
+            .with_actual(problems.join(", "))
+            .fail();
+    }
+
+    /// Asserts that every key and value of the subject hashmap together satisfy the provided
+    /// predicate. All of the entries which don't are reported.
+    ///
+    /// ```rust,ignore
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("one", "1");
+    ///
+    /// assert_that(&test_map).each_entry_matching(|key, value| key.len() > value.len());
+    /// ```
+    fn each_entry_matching<F: Fn(&K, &V) -> bool>(&mut self, predicate: F) {
+        let mut failing_entries: Vec<String> = self.subject
+            .iter()
+            .filter(|&(key, value)| !predicate(key, value))
+            .map(|(key, value)| format!("({:?}, {:?})", key, value))
+            .collect();
+
+        if !failing_entries.is_empty() {
+            // Sort so that the failure message doesn't depend on the hashmap's iteration order.
+            failing_entries.sort();
+
+            AssertionFailure::from_spec(self)
+                .with_expected("all entries to match predicate".to_string())
+                .with_actual(format!("entries not matching <[{}]>", failing_entries.join(", ")))
+                .fail();
+        }
+    }
+
+    /// Counts the entries of the subject hashmap whose key and value together satisfy the provided
+    /// predicate, then passes a `Spec` over the count to the provided assertions.
+    ///
+    /// ```rust,ignore
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("hello", "hi");
+    ///
+    /// assert_that(&test_map).entry_count_satisfying_and_assert(|_, value| value.len() == 2,
+    ///                                                          |mut count| count.is_equal_to(1));
+    /// ```
+    fn entry_count_satisfying_and_assert<F, G>(&mut self, predicate: F, assertions: G)
+        where F: Fn(&K, &V) -> bool,
+              G: FnOnce(Spec<usize>)
+    {
+        let count = self.subject.iter().filter(|&(key, value)| predicate(key, value)).count();
+
+        assertions(Spec {
+            subject: &count,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
+
+    /// Asserts that the subject hashmap contains every key of the other hashmap, and that the
+    /// values are equal for every key the two hashmaps share. Unlike comparing the hashmaps for
+    /// equality, the subject may also contain keys which the other hashmap doesn't. This is
+    /// equivalent to `is_superset_map_of`, and fails with the same message.
+    ///
+    /// ```rust,ignore
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("host", "localhost");
+    /// test_map.insert("port", "8080");
+    ///
+    /// let mut defaults = HashMap::new();
+    /// defaults.insert("host", "localhost");
+    ///
+    /// assert_that(&test_map).merged_all_values_equal(&defaults);
+    /// ```
+    fn merged_all_values_equal(&mut self, other: &HashMap<K, V>) {
+        self.is_superset_map_of(other);
+    }
+
+    /// Asserts that every entry of the expected hashmap is present in the subject hashmap with an
+    /// equal value. The subject may contain other entries as well. Missing keys and differing
+    /// values are reported separately. This is equivalent to `merged_all_values_equal`.
+    ///
+    /// ```rust,ignore
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("host", "localhost");
+    /// test_map.insert("port", "8080");
+    ///
+    /// let mut required = HashMap::new();
+    /// required.insert("host", "localhost");
+    ///
+    /// assert_that(&test_map).is_superset_map_of(&required);
+    /// ```
+    fn is_superset_map_of<E: Borrow<HashMap<K, V>>>(&mut self, expected: E) {
+        let problems = describe_missing_entries(self.subject, expected.borrow());
+
+        if !problems.is_empty() {
+            AssertionFailure::from_spec(self)
+                .with_expected("hashmap containing every entry of the expected hashmap".to_string())
+                .with_actual(problems.join(", "))
+                .fail();
+        }
+    }
+
+    /// Maps each value of the subject hashmap with the provided function, then passes a `Spec`
+    /// over the resulting hashmap to the provided assertions.
+    ///
+    /// ```rust,ignore
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("hello", "hi");
+    ///
+    /// assert_that(&test_map).transform_values_and_assert(|value| value.len(), |mut lengths| {
+    ///     lengths.contains_entry("hello", 2);
+    /// });
+    /// ```
+    fn transform_values_and_assert<U, F, G>(&mut self, f: F, assertions: G)
+        where K: Clone,
+              U: Debug,
+              F: Fn(&V) -> U,
+              G: FnOnce(Spec<HashMap<K, U>>)
+    {
+        let transformed: HashMap<K, U> = self.subject
+            .iter()
+            .map(|(key, value)| (key.clone(), f(value)))
+            .collect();
+
+        assertions(Spec {
+            subject: &transformed,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
+
+    /// Asserts that no two keys of the subject hashmap have equal values. Every duplicated value
+    /// is reported along with the keys it appears under.
+    ///
+    /// ```rust,ignore
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("alice", 1);
+    /// test_map.insert("bob", 2);
+    ///
+    /// assert_that(&test_map).all_values_are_unique();
+    /// ```
+    fn all_values_are_unique(&mut self)
+        where V: Hash + Eq
+    {
+        let mut keys_by_value: HashMap<&V, Vec<&K>> = HashMap::new();
+
+        for (key, value) in self.subject {
+            keys_by_value.entry(value).or_default().push(key);
+        }
+
+        let mut duplicates: Vec<String> = keys_by_value.into_iter()
+            .filter(|(_, keys)| keys.len() > 1)
+            .map(|(value, keys)| {
+                format!("<{:?}> for keys <[{}]>", value, describe_keys(keys.into_iter()).join(", "))
+            })
+            .collect();
+
+        // Sort so that the failure message doesn't depend on the iteration order of the hashmap.
+        duplicates.sort();
+
+        if !duplicates.is_empty() {
+            AssertionFailure::from_spec(self)
+                .with_expected("hashmap with unique values".to_string())
+                .with_actual(format!("duplicate values {}", duplicates.join(", ")))
+                .fail();
+        }
+    }
+}
+
+impl<'s, K, V> HashMapOptionAssertions for Spec<'s, HashMap<K, Option<V>>>
+    where K: Hash + Eq + Debug
+{
+    /// Asserts that every value of the subject hashmap is `Some`. All of the keys with `None`
+    /// values are reported. The subject type must be a `HashMap` of `Option` values.
+    ///
+    /// ```rust,ignore
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("hello", Some("hi"));
+    ///
+    /// assert_that(&test_map).has_no_none_values();
+    /// ```
+    fn has_no_none_values(&mut self) {
+        let none_keys = self.subject
+            .iter()
+            .filter(|&(_, value)| value.is_none())
+            .map(|(key, _)| key);
+        let described_none_keys = describe_keys(none_keys);
+
+        if !described_none_keys.is_empty() {
+            AssertionFailure::from_spec(self)
+                .with_expected("all values to be option[some]".to_string())
+                .with_actual(format!("option[none] for keys <[{}]>",
+                                     described_none_keys.join(", ")))
+                .fail();
+        }
+    }
+}
+
+impl<'s, K> HashMapCountAssertions<K> for Spec<'s, HashMap<K, usize>>
+    where K: Hash + Eq + Debug
+{
+    /// Asserts that the expected key has the highest count in the subject hashmap. The subject
+    /// type must be a `HashMap` of `usize` counts. Fails if another key has the same count.
+    ///
+    /// ```rust,ignore
+    /// let mut test_map = HashMap::new();
+    /// test_map.insert("hello", 3);
+    /// test_map.insert("hi", 1);
+    ///
+    /// assert_that(&test_map).most_frequent_key_is("hello");
+    /// ```
+    fn most_frequent_key_is<EK: Borrow<K>>(&mut self, expected_key: EK) {
+        let borrowed_expected_key = expected_key.borrow();
+        let expected_message = format!("most frequent key to be <{:?}>", borrowed_expected_key);
+
+        let max_count = match self.subject.values().max() {
+            Some(max_count) => *max_count,
+            None => {
+                AssertionFailure::from_spec(self)
+                    .with_expected(expected_message)
+                    .with_actual("an empty hashmap".to_string())
+                    .fail();
+
+                unreachable!();
+            }
+        };
+
+        let top_keys: Vec<&K> = self.subject
+            .iter()
+            .filter(|&(_, count)| *count == max_count)
+            .map(|(key, _)| key)
+            .collect();
+
+        if top_keys.len() == 1 && top_keys[0] == borrowed_expected_key {
+            return;
+        }
+
+        let described_top_keys = describe_keys(top_keys.into_iter());
+        let actual_message = if described_top_keys.len() == 1 {
+            format!("<{}> with count <{}>", described_top_keys[0], max_count)
+        } else {
+            format!("<[{}]> tied with count <{}>", described_top_keys.join(", "), max_count)
+        };
+
+        AssertionFailure::from_spec(self)
+            .with_expected(expected_message)
+            .with_actual(actual_message)
+            .fail();
+    }
+}
+
+fn describe_missing_entries<K, V>(subject: &HashMap<K, V>, expected: &HashMap<K, V>) -> Vec<String>
+    where K: Hash + Eq + Debug,
+          V: PartialEq + Debug
+{
+    let missing_keys = expected.keys().filter(|key| !subject.contains_key(key));
+    let mut problems = describe_key_differences(missing_keys, iter::empty());
+
+    let mut differing_values: Vec<String> = subject.iter()
+        .filter_map(|(key, value)| {
+            expected.get(key)
+                .filter(|expected_value| *expected_value != value)
+                .map(|expected_value| {
+                    format!("key <{:?}> with value <{:?}> instead of <{:?}>",
+                            key,
+                            value,
+                            expected_value)
+                })
+        })
+        .collect();
+
+    // Sort so that the failure message doesn't depend on the iteration order of the hashmaps.
+    differing_values.sort();
+    problems.extend(differing_values);
+    problems
+}
+
+fn describe_key_differences<'a, K, M, E>(missing_keys: M, extra_keys: E) -> Vec<String>
+    where K: 'a + Debug,
+          M: Iterator<Item = &'a K>,
+          E: Iterator<Item = &'a K>
+{
+    let mut differences = vec![];
+    let described_missing_keys = describe_keys(missing_keys);
+    let described_extra_keys = describe_keys(extra_keys);
+
+    if !described_missing_keys.is_empty() {
+        differences.push(format!("missing keys <[{}]>", described_missing_keys.join(", ")));
+    }
+
+    if !described_extra_keys.is_empty() {
+        differences.push(format!("extra keys <[{}]>", described_extra_keys.join(", ")));
+    }
+
+    differences
+}
+
+fn describe_keys<'a, K: 'a + Debug, I: Iterator<Item = &'a K>>(keys: I) -> Vec<String> {
+    let mut described_keys: Vec<String> = keys.map(|key| format!("{:?}", key)).collect();
+
+    // Sort so that the failure message doesn't depend on the iteration order of the hashmap.
+    described_keys.sort();
+    described_keys
 }
 
 #[cfg(test)]
@@ -359,4 +830,376 @@ mod tests {
 
         assert_that(&test_map).does_not_contain_entry(&"hello", &"hi");
     }
+
+    #[test]
+    fn should_not_panic_if_merged_hashmap_satisfies_assertions() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", "hi");
+        test_map.insert("hey", "hi");
+
+        let mut other_map = HashMap::new();
+        other_map.insert("hello", "hello");
+        other_map.insert("howdy", "hi");
+
+        assert_that(&test_map).merged_with_and_assert(&other_map, |mut spec| {
+            spec.has_length(3);
+            spec.contains_entry("hello", "hi");
+            spec.contains_entry("howdy", "hi");
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: hashmap to have length <3>\n\t but was: <2>")]
+    fn should_panic_if_merged_hashmap_does_not_satisfy_assertions() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", "hi");
+
+        let mut other_map = HashMap::new();
+        other_map.insert("hello", "hello");
+        other_map.insert("hey", "hi");
+
+        assert_that(&test_map).merged_with_and_assert(&other_map, |mut spec| spec.has_length(3));
+    }
+
+    #[test]
+    fn should_not_panic_if_hashmap_values_are_close_to_expected() {
+        let mut test_map = HashMap::new();
+        test_map.insert("latency", 10.02);
+        test_map.insert("throughput", 499.95);
+
+        let mut expected_map = HashMap::new();
+        expected_map.insert("latency", 10.0);
+        expected_map.insert("throughput", 500.0);
+
+        assert_that(&test_map).values_are_close_to(&expected_map, 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: hashmap with the expected keys and values within \
+                   <0.25> of them\
+                   \n\t but was: key <\"latency\"> with value <10.5> differing by <0.5>")]
+    fn should_panic_if_hashmap_value_is_not_close_to_expected() {
+        let mut test_map = HashMap::new();
+        test_map.insert("latency", 10.5);
+        test_map.insert("throughput", 500.0);
+
+        let mut expected_map = HashMap::new();
+        expected_map.insert("latency", 10.0);
+        expected_map.insert("throughput", 500.0);
+
+        assert_that(&test_map).values_are_close_to(&expected_map, 0.25);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: hashmap with the expected keys and values within \
+                   <0.25> of them\
+                   \n\t but was: missing keys <[\"errors\", \"latency\", \"throughput\"]>, \
+                   extra keys <[\"latency_ms\"]>")]
+    fn should_panic_if_hashmap_keys_differ_from_expected_when_values_are_close() {
+        let mut test_map = HashMap::new();
+        test_map.insert("latency_ms", 10.0);
+
+        let mut expected_map = HashMap::new();
+        expected_map.insert("latency", 10.0);
+        expected_map.insert("throughput", 500.0);
+        expected_map.insert("errors", 0.0);
+
+        assert_that(&test_map).values_are_close_to(&expected_map, 0.25);
+    }
+
+    #[test]
+    fn should_not_panic_if_each_hashmap_entry_matches_predicate() {
+        let mut test_map = HashMap::new();
+        test_map.insert("one", "aaa");
+        test_map.insert("three", "bbbbb");
+
+        assert_that(&test_map).each_entry_matching(|key, value| key.len() == value.len());
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: all entries to match predicate\
+                   \n\t but was: entries not matching <[(\"four\", \"bbb\"), (\"two\", \"a\")]>")]
+    fn should_panic_if_hashmap_entries_do_not_match_predicate() {
+        let mut test_map = HashMap::new();
+        test_map.insert("one", "aaa");
+        test_map.insert("two", "a");
+        test_map.insert("four", "bbb");
+
+        assert_that(&test_map).each_entry_matching(|key, value| key.len() == value.len());
+    }
+
+    #[test]
+    fn should_not_panic_if_hashmap_has_exactly_keys() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", "hi");
+        test_map.insert("hey", "hi");
+
+        assert_that(&test_map).has_exactly_keys(&["hey", "hello"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: hashmap with exactly keys <[\"hello\", \"howdy\"]>\
+                   \n\t but was: missing keys <[\"howdy\"]>, extra keys <[\"hey\", \"hi\"]>")]
+    fn should_panic_if_hashmap_does_not_have_exactly_keys() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", "hi");
+        test_map.insert("hey", "hi");
+        test_map.insert("hi", "hi");
+
+        assert_that(&test_map).has_exactly_keys(&["hello", "howdy"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: hashmap with exactly keys <[\"hello\"]>\
+                   \n\t but was: extra keys <[\"hey\"]>")]
+    fn should_panic_if_hashmap_has_extra_keys() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", "hi");
+        test_map.insert("hey", "hi");
+
+        assert_that(&test_map).has_exactly_keys(&["hello"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [headers]\
+                   \n\texpected: hashmap with exactly keys <[\"hello\", \"hey\", \"howdy\"]>\
+                   \n\t but was: missing keys <[\"hey\", \"howdy\"]>")]
+    fn should_panic_if_hashmap_has_missing_keys() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", "hi");
+
+        assert_that(&test_map).named("headers").has_exactly_keys(&["hello", "hey", "howdy"]);
+    }
+
+    #[test]
+    fn should_not_panic_if_hashmap_has_no_none_values() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", Some("hi"));
+        test_map.insert("hey", Some("hi"));
+
+        assert_that(&test_map).has_no_none_values();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: all values to be option[some]\
+                   \n\t but was: option[none] for keys <[\"hey\", \"howdy\"]>")]
+    fn should_panic_if_hashmap_has_none_values() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", Some("hi"));
+        test_map.insert("howdy", None);
+        test_map.insert("hey", None);
+
+        assert_that(&test_map).has_no_none_values();
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_count_of_hashmap_entries_satisfying_predicate() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", "hi");
+        test_map.insert("hey", "hello");
+        test_map.insert("howdy", "hey");
+
+        assert_that(&test_map)
+            .entry_count_satisfying_and_assert(|key, value| key.len() > value.len(), |mut count| {
+                count.is_equal_to(2);
+                count.is_greater_than(1);
+            });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [greetings]\n\texpected: <1>\n\t but was: <0>")]
+    fn should_panic_if_count_of_hashmap_entries_satisfying_predicate_does_not_match() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", "hi");
+
+        assert_that(&test_map)
+            .named("greetings")
+            .entry_count_satisfying_and_assert(|key, _| key.is_empty(), |mut count| {
+                count.is_equal_to(1);
+            });
+    }
+
+    #[test]
+    fn should_not_panic_if_merged_values_are_equal() {
+        let mut test_map = HashMap::new();
+        test_map.insert("host", "localhost");
+        test_map.insert("port", "8080");
+
+        let mut other_map = HashMap::new();
+        other_map.insert("host", "localhost");
+
+        assert_that(&test_map).merged_all_values_equal(&other_map);
+        assert_that(&test_map).merged_all_values_equal(&test_map.clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [config]\
+                   \n\texpected: hashmap containing every entry of the expected hashmap\
+                   \n\t but was: missing keys <[\"user\"]>, \
+                   key <\"port\"> with value <\"8080\"> instead of <\"80\">")]
+    fn should_panic_if_merged_values_are_not_equal() {
+        let mut test_map = HashMap::new();
+        test_map.insert("host", "localhost");
+        test_map.insert("port", "8080");
+
+        let mut other_map = HashMap::new();
+        other_map.insert("host", "localhost");
+        other_map.insert("port", "80");
+        other_map.insert("user", "admin");
+
+        assert_that(&test_map).named("config").merged_all_values_equal(&other_map);
+    }
+
+    #[test]
+    fn should_not_panic_if_hashmap_length_matches_predicate() {
+        let mut test_map = HashMap::new();
+        test_map.insert(1, 1);
+        test_map.insert(2, 2);
+
+        assert_that(&test_map).has_length_matching(|length| length % 2 == 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: hashmap to have length matching predicate\
+                   \n\t but was: <1>")]
+    fn should_panic_if_hashmap_length_does_not_match_predicate() {
+        let mut test_map = HashMap::new();
+        test_map.insert(1, 1);
+
+        assert_that(&test_map).has_length_matching(|length| length % 2 == 0);
+    }
+
+    #[test]
+    fn should_not_panic_if_hashmap_is_superset_of_expected() {
+        let mut test_map = HashMap::new();
+        test_map.insert("host", "localhost");
+        test_map.insert("port", "8080");
+
+        let mut expected_map = HashMap::new();
+        expected_map.insert("host", "localhost");
+
+        assert_that(&test_map).is_superset_map_of(&expected_map);
+        assert_that(&test_map).is_superset_map_of(HashMap::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [config]\
+                   \n\texpected: hashmap containing every entry of the expected hashmap\
+                   \n\t but was: missing keys <[\"user\"]>\n")]
+    fn should_panic_if_superset_hashmap_is_missing_key() {
+        let mut test_map = HashMap::new();
+        test_map.insert("host", "localhost");
+
+        let mut expected_map = HashMap::new();
+        expected_map.insert("host", "localhost");
+        expected_map.insert("user", "admin");
+
+        assert_that(&test_map).named("config").is_superset_map_of(&expected_map);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: hashmap containing every entry of the expected hashmap\
+                   \n\t but was: key <\"port\"> with value <\"8080\"> instead of <\"80\">\n")]
+    fn should_panic_if_superset_hashmap_has_different_value() {
+        let mut test_map = HashMap::new();
+        test_map.insert("host", "localhost");
+        test_map.insert("port", "8080");
+
+        let mut expected_map = HashMap::new();
+        expected_map.insert("port", "80");
+
+        assert_that(&test_map).is_superset_map_of(&expected_map);
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_transformed_values() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", "hi");
+        test_map.insert("hey", "hey");
+
+        assert_that(&test_map).transform_values_and_assert(|value| value.len(), |mut lengths| {
+            lengths.contains_entry("hello", 2);
+            lengths.contains_entry("hey", 3);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [greetings]\
+                   \n\texpected: hashmap containing key <\"hello\"> with value <3>\
+                   \n\t but was: key <\"hello\"> with value <2> instead")]
+    fn should_panic_with_subject_name_if_transformed_values_assertion_fails() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", "hi");
+
+        assert_that(&test_map)
+            .named("greetings")
+            .transform_values_and_assert(|value| value.len(),
+                                         |mut lengths| lengths.contains_entry("hello", 3));
+    }
+
+    #[test]
+    fn should_not_panic_if_hashmap_values_are_unique() {
+        let mut test_map = HashMap::new();
+        test_map.insert("alice", 1);
+        test_map.insert("bob", 2);
+
+        assert_that(&test_map).all_values_are_unique();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [user ids]\n\texpected: hashmap with unique values\
+                   \n\t but was: duplicate values <1> for keys <[\"alice\", \"carol\"]>, \
+                   <2> for keys <[\"bob\", \"dave\", \"erin\"]>")]
+    fn should_panic_if_hashmap_values_are_not_unique() {
+        let mut test_map = HashMap::new();
+        test_map.insert("alice", 1);
+        test_map.insert("bob", 2);
+        test_map.insert("carol", 1);
+        test_map.insert("dave", 2);
+        test_map.insert("erin", 2);
+        test_map.insert("frank", 3);
+
+        assert_that(&test_map).named("user ids").all_values_are_unique();
+    }
+
+    #[test]
+    fn should_not_panic_if_key_is_most_frequent() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", 3);
+        test_map.insert("hi", 1);
+        test_map.insert("hey", 2);
+
+        assert_that(&test_map).most_frequent_key_is("hello");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: most frequent key to be <\"hi\">\
+                   \n\t but was: <\"hello\"> with count <3>")]
+    fn should_panic_if_key_is_not_most_frequent() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", 3);
+        test_map.insert("hi", 1);
+
+        assert_that(&test_map).most_frequent_key_is("hi");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: most frequent key to be <\"hi\">\
+                   \n\t but was: <[\"hello\", \"hi\"]> tied with count <2>")]
+    fn should_panic_if_most_frequent_key_is_tied() {
+        let mut test_map = HashMap::new();
+        test_map.insert("hello", 2);
+        test_map.insert("hi", 2);
+
+        assert_that(&test_map).most_frequent_key_is("hi");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: most frequent key to be <\"hi\">\
+                   \n\t but was: an empty hashmap")]
+    fn should_panic_if_hashmap_of_counts_is_empty() {
+        let test_map: HashMap<&str, usize> = HashMap::new();
+
+        assert_that(&test_map).most_frequent_key_is("hi");
+    }
 }
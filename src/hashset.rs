@@ -0,0 +1,251 @@
+use super::{AssertionFailure, Spec};
+
+use std::borrow::Borrow;
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+pub trait HashSetAssertions<'s, V: 's + Hash + Eq> {
+    fn mapped_to_vec_and_assert<F>(&mut self, f: F)
+        where V: Ord + Clone,
+              F: Fn(Spec<Vec<V>>);
+    fn count_satisfying_and_assert<F, G>(&mut self, predicate: F, assertions: G)
+        where F: Fn(&V) -> bool,
+              G: FnOnce(Spec<usize>);
+    fn has_length_matching<F: Fn(usize) -> bool>(&mut self, predicate: F);
+    fn find_value<E: Borrow<V>>(&mut self, expected: E) -> Option<Spec<'s, V>>;
+    fn contains_value_owned(&mut self, expected: V) where V: Debug;
+}
+
+impl<'s, V> HashSetAssertions<'s, V> for Spec<'s, HashSet<V>>
+    where V: Hash + Eq
+{
+    /// Converts the subject hashset into a sorted `Vec` and passes a `Spec` over it to the
+    /// provided function, so that assertions depending on order are stable. The subject type
+    /// must be of `HashSet`, and the contained type must implement `Ord`.
+    ///
+    /// ```rust,ignore
+    /// let mut test_set = HashSet::new();
+    /// test_set.insert(2);
+    /// test_set.insert(1);
+    ///
+    /// assert_that(&test_set).mapped_to_vec_and_assert(|mut spec| spec.is_equal_to(vec![1, 2]));
+    /// ```
+    fn mapped_to_vec_and_assert<F>(&mut self, f: F)
+        where V: Ord + Clone,
+              F: Fn(Spec<Vec<V>>)
+    {
+        let mut sorted: Vec<V> = self.subject.iter().cloned().collect();
+        sorted.sort();
+
+        f(Spec {
+            subject: &sorted,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
+
+    /// Counts the values of the subject hashset which satisfy the provided predicate, then passes
+    /// a `Spec` over the count to the provided assertions.
+    ///
+    /// ```rust,ignore
+    /// let mut test_set = HashSet::new();
+    /// test_set.insert(2);
+    /// test_set.insert(1);
+    ///
+    /// assert_that(&test_set).count_satisfying_and_assert(|value| value % 2 == 0,
+    ///                                                    |mut count| count.is_equal_to(1));
+    /// ```
+    fn count_satisfying_and_assert<F, G>(&mut self, predicate: F, assertions: G)
+        where F: Fn(&V) -> bool,
+              G: FnOnce(Spec<usize>)
+    {
+        let count = self.subject.iter().filter(|value| predicate(value)).count();
+
+        assertions(Spec {
+            subject: &count,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
+
+    /// Asserts that the length of the subject hashset satisfies the provided predicate. The subject
+    /// type must be of `HashSet`.
+    ///
+    /// ```rust,ignore
+    /// let mut test_set = HashSet::new();
+    /// test_set.insert(2);
+    /// test_set.insert(1);
+    ///
+    /// assert_that(&test_set).has_length_matching(|length| length % 2 == 0);
+    /// ```
+    fn has_length_matching<F: Fn(usize) -> bool>(&mut self, predicate: F) {
+        let length = self.subject.len();
+
+        if !predicate(length) {
+            AssertionFailure::from_spec(self)
+                .with_expected("hashset to have length matching predicate".to_string())
+                .with_actual(format!("<{}>", length))
+                .fail();
+        }
+    }
+
+    /// Returns a new `Spec` over the value of the subject hashset which is equal to the expected
+    /// value, or `None` if there is no such value. Unlike other assertions this never fails, so
+    /// that further assertions can be made conditionally.
+    ///
+    /// ```rust,ignore
+    /// let mut test_set = HashSet::new();
+    /// test_set.insert("admin");
+    ///
+    /// if let Some(mut role) = assert_that(&test_set).find_value("admin") {
+    ///     role.is_equal_to("admin");
+    /// }
+    /// ```
+    fn find_value<E: Borrow<V>>(&mut self, expected: E) -> Option<Spec<'s, V>> {
+        let subject: &'s HashSet<V> = self.subject;
+
+        subject.get(expected.borrow()).map(|value| {
+            Spec {
+                subject: value,
+                subject_name: self.subject_name,
+                location: self.location.clone(),
+                description: self.description,
+            }
+        })
+    }
+
+    /// Asserts that the subject hashset contains the expected value. The expected value is taken
+    /// by value, which is convenient for `Copy` types such as integers.
+    ///
+    /// ```rust,ignore
+    /// let mut test_set = HashSet::new();
+    /// test_set.insert(1);
+    ///
+    /// assert_that(&test_set).contains_value_owned(1);
+    /// ```
+    fn contains_value_owned(&mut self, expected: V)
+        where V: Debug
+    {
+        if !self.subject.contains(&expected) {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("hashset to contain <{:?}>", expected))
+                .with_actual(format!("<{:?}>", self.subject))
+                .fail();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+
+    use std::collections::HashSet;
+
+    #[test]
+    fn should_not_panic_if_sorted_hashset_matches_expected() {
+        let mut test_set = HashSet::new();
+        test_set.insert(3);
+        test_set.insert(1);
+        test_set.insert(2);
+
+        assert_that(&test_set).mapped_to_vec_and_assert(|mut spec| spec.is_equal_to(vec![1, 2, 3]));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [numbers]\n\texpected: <[3, 2, 1]>\
+                   \n\t but was: <[1, 2, 3]>")]
+    fn should_panic_if_sorted_hashset_does_not_match_expected() {
+        let mut test_set = HashSet::new();
+        test_set.insert(3);
+        test_set.insert(1);
+        test_set.insert(2);
+
+        assert_that(&test_set)
+            .named("numbers")
+            .mapped_to_vec_and_assert(|mut spec| spec.is_equal_to(vec![3, 2, 1]));
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_count_of_hashset_values_satisfying_predicate() {
+        let test_set: HashSet<u8> = (1..7).collect();
+
+        assert_that(&test_set).count_satisfying_and_assert(|value| value % 2 == 0, |mut count| {
+            count.is_equal_to(3);
+            count.is_less_than(4);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value greater than <0>\n\t but was: <0>")]
+    fn should_panic_if_count_of_hashset_values_satisfying_predicate_does_not_match() {
+        let test_set: HashSet<u8> = (1..7).collect();
+
+        assert_that(&test_set)
+            .count_satisfying_and_assert(|value| *value > 10, |mut count| count.is_greater_than(0));
+    }
+
+    #[test]
+    fn should_not_panic_if_hashset_length_matches_predicate() {
+        let mut test_set = HashSet::new();
+        test_set.insert(1);
+        test_set.insert(2);
+
+        assert_that(&test_set).has_length_matching(|length| length % 2 == 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [tags]\
+                   \n\texpected: hashset to have length matching predicate\
+                   \n\t but was: <3>")]
+    fn should_panic_if_hashset_length_does_not_match_predicate() {
+        let mut test_set = HashSet::new();
+        test_set.insert(1);
+        test_set.insert(2);
+        test_set.insert(3);
+
+        assert_that(&test_set).named("tags").has_length_matching(|length| length % 2 == 0);
+    }
+
+    #[test]
+    fn should_return_spec_over_value_found_in_hashset() {
+        let mut test_set = HashSet::new();
+        test_set.insert("admin");
+        test_set.insert("user");
+
+        let mut role = assert_that(&test_set).named("roles").find_value("admin").unwrap();
+        role.is_equal_to("admin");
+        assert_that(&role.subject_name).is_equal_to(Some("roles"));
+    }
+
+    #[test]
+    fn should_return_none_without_panicking_if_value_is_not_in_hashset() {
+        let mut test_set = HashSet::new();
+        test_set.insert("user");
+
+        assert_that(&assert_that(&test_set).find_value("admin").is_none()).is_true();
+    }
+
+    #[test]
+    fn should_not_panic_if_hashset_contains_owned_value() {
+        let mut test_set = HashSet::new();
+        test_set.insert(1);
+        test_set.insert(2);
+
+        assert_that(&test_set).contains_value_owned(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [ids]\n\texpected: hashset to contain <3>\
+                   \n\t but was: <{1}>")]
+    fn should_panic_if_hashset_does_not_contain_owned_value() {
+        let mut test_set = HashSet::new();
+        test_set.insert(1);
+
+        assert_that(&test_set).named("ids").contains_value_owned(3);
+    }
+
+}
@@ -15,6 +15,7 @@ macro_rules! generate_iter_spec_trait {
                 fn does_not_contain<E: 's + Borrow<T>>(&mut self, expected_value: E);
                 fn equals_iterator<E: 's>(&mut self, expected_iter: &'s E)
                     where E: Iterator<Item = &'s T> + Clone;
+                fn starts_with<E: Borrow<[T]>>(&mut self, expected: E);
             }
     }
 }
@@ -88,6 +89,19 @@ impl<'s, T: 's, I> ContainingIntoIterAssertions<'s, T> for Spec<'s, I>
     {
         compare_iterators(self, self.subject.into_iter(), expected_iter.clone());
     }
+
+    /// Asserts that the first items of the iterable subject are equal to the provided items. Only
+    /// as many items as were provided are read from the subject. The subject must implement
+    /// `IntoIterator`, and the contained type must implement `PartialEq` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1,2,3];
+    /// assert_that(&test_vec).starts_with([1, 2]);
+    /// ```
+    fn starts_with<E: Borrow<[T]>>(&mut self, expected: E) {
+        let subject_iter = self.subject.into_iter();
+        check_iterator_starts_with(self, subject_iter, expected.borrow());
+    }
 }
 
 impl<'s, T: 's, I> ContainingIteratorAssertions<'s, T> for Spec<'s, I>
@@ -147,6 +161,20 @@ impl<'s, T: 's, I> ContainingIteratorAssertions<'s, T> for Spec<'s, I>
     {
         compare_iterators(self, self.subject.clone(), expected_iter.clone());
     }
+
+    /// Asserts that the first items of the iterable subject are equal to the provided items. Only
+    /// as many items as were provided are read from the subject, so this can be used with
+    /// iterators which are very long or never complete. The subject must implement `Iterator`,
+    /// and the contained type must implement `PartialEq` and `Debug`.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1,2,3];
+    /// assert_that(&test_vec.iter()).starts_with([1, 2]);
+    /// ```
+    fn starts_with<E: Borrow<[T]>>(&mut self, expected: E) {
+        let subject_iter = self.subject.clone();
+        check_iterator_starts_with(self, subject_iter, expected.borrow());
+    }
 }
 
 impl<'s, T: 's, I> MappingIterAssertions<'s, T> for Spec<'s, I>
@@ -343,6 +371,32 @@ fn compare_iterators<T, V, I, E>(spec: &mut Spec<T>, actual_iter: I, expected_it
     }
 }
 
+fn check_iterator_starts_with<'s, T, V, I>(spec: &mut Spec<T>, actual_iter: I, expected: &[V])
+    where V: 's + PartialEq + Debug,
+          I: Iterator<Item = &'s V>
+{
+    let mut actual_iter = actual_iter;
+    let mut read_subject = vec![];
+
+    for (index, expected_item) in expected.iter().enumerate() {
+        let actual_message = match actual_iter.next() {
+            Some(actual) if actual.eq(expected_item) => {
+                read_subject.push(actual);
+                continue;
+            }
+            Some(actual) => {
+                format!("<{:?}> at index <{}> (read <{:?}>)", actual, index, read_subject)
+            }
+            None => format!("Completed iterator (read <{:?}>)", read_subject),
+        };
+
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("iterator starting with <{:?}>", expected))
+            .with_actual(actual_message)
+            .fail();
+    }
+}
+
 fn panic_unmatched<T, E: Debug, A: Debug>(spec: &mut Spec<T>,
                                           expected: E,
                                           actual: A,
@@ -587,4 +641,33 @@ mod tests {
         Bad,
     }
 
+    #[test]
+    fn should_not_panic_if_iterable_starts_with_expected_items() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec).starts_with([1, 2]);
+        assert_that(&test_vec).starts_with(&test_vec[..]);
+    }
+
+    #[test]
+    fn should_not_panic_if_unbounded_iterator_starts_with_expected_items() {
+        let test_items = [0, 2, 4];
+        assert_that(&test_items.iter().cycle()).starts_with([0, 2, 4, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator starting with <[1, 5, 3]>\
+                   \n\t but was: <2> at index <1> (read <[1]>)")]
+    fn should_panic_if_iterator_does_not_start_with_expected_items() {
+        let test_items = [1, 2, 3];
+        assert_that(&test_items.iter()).starts_with([1, 5, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: iterator starting with <[1, 2, 3, 4]>\
+                   \n\t but was: Completed iterator (read <[1, 2, 3]>)")]
+    fn should_panic_if_iterator_completes_before_expected_items() {
+        let test_items = [1, 2, 3];
+        assert_that(&test_items.iter()).starts_with(vec![1, 2, 3, 4]);
+    }
+
 }
@@ -141,18 +141,28 @@
 
 use std::borrow::Borrow;
 use std::cmp::PartialEq;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
+use std::mem;
 
 use colours::{TERM_RED, TERM_BOLD, TERM_RESET};
 
+#[cfg(feature = "approx")]
+pub mod approx;
 pub mod boolean;
+pub mod btreemap;
+pub mod checked;
 pub mod hashmap;
+pub mod hashset;
 pub mod numeric;
 pub mod option;
 pub mod path;
 pub mod prelude;
 pub mod result;
 pub mod string;
+#[cfg(feature = "time")]
+pub mod time;
+#[cfg(feature = "url")]
+pub mod url;
 pub mod vec;
 pub mod iter;
 
@@ -172,9 +182,30 @@ mod colours {
     pub const TERM_RESET: &'static str = "";
 }
 
+// Renamed so that it doesn't clash with the `approx` module.
+#[cfg(feature = "approx")]
+extern crate approx as approx_crate;
+
 #[cfg(feature = "num")]
 extern crate num;
 
+#[cfg(feature = "serde")]
+extern crate serde_json;
+
+#[cfg(feature = "toml")]
+extern crate toml;
+
+#[cfg(feature = "yaml")]
+extern crate serde_yaml;
+
+// Renamed so that it doesn't clash with the `time` module.
+#[cfg(feature = "time")]
+extern crate time as time_crate;
+
+// Renamed so that it doesn't clash with the `url` module.
+#[cfg(feature = "url")]
+extern crate url as url_crate;
+
 #[macro_export]
 macro_rules! assert_that {
     (&$subject:tt) => {
@@ -199,6 +230,20 @@ macro_rules! assert_that {
     };
 }
 
+/// Alternative name for the `assert_that!` macro, which accepts the subject either by value or
+/// by reference and records the file and line of the assertion.
+///
+/// ```rust,ignore
+/// spec!(test_vec).has_length(5);
+/// spec!(&test_vec).has_length(5);
+/// ```
+#[macro_export]
+macro_rules! spec {
+    ($($subject:tt)+) => {
+        assert_that!($($subject)+)
+    };
+}
+
 #[macro_export]
 macro_rules! asserting {
     (&$description:tt) => {
@@ -213,6 +258,33 @@ macro_rules! asserting {
     };
 }
 
+/// Asserts that two values are equal once the listed fields are ignored.
+///
+/// As fields can't be skipped without reflection, both values are cloned and the listed fields are
+/// reset to their `Default` value before the copies are compared with `is_equal_to`. The values
+/// must implement `Clone`, `PartialEq` and `Debug`, and the ignored fields must implement
+/// `Default`.
+///
+/// ```rust,ignore
+/// assert_eq_ignoring!(saved_event, expected_event, [id, created_at]);
+/// ```
+#[macro_export]
+macro_rules! assert_eq_ignoring {
+    ($subject:expr, $expected:expr, [$($field:ident),+ $(,)*]) => {
+        {
+            let line = line!();
+            let file =  file!();
+            let mut subject = $subject.clone();
+            let mut expected = $expected.clone();
+            $(
+                subject.$field = ::std::default::Default::default();
+                expected.$field = ::std::default::Default::default();
+            )+
+            assert_that(&subject).at_location(format!("{}:{}", file, line)).is_equal_to(expected)
+        }
+    };
+}
+
 pub trait DescriptiveSpec<'r> {
     fn subject_name(&self) -> Option<&'r str>;
     fn location(&self) -> Option<String>;
@@ -227,6 +299,7 @@ pub struct AssertionFailure<'r, T: 'r> {
     spec: &'r T,
     expected: Option<String>,
     actual: Option<String>,
+    notes: Vec<String>,
 }
 
 /// A description for an assertion.
@@ -262,6 +335,12 @@ pub fn assert_that<'s, S>(subject: &'s S) -> Spec<'s, S> {
     }
 }
 
+/// Wraps a mutably borrowed subject in a `Spec`, reborrowing it as shared so that the usual
+/// read-only assertions are available without writing `assert_that(&*subject)`.
+pub fn assert_that_mut<'s, S>(subject: &'s mut S) -> Spec<'s, S> {
+    assert_that(&*subject)
+}
+
 /// Describes an assertion.
 pub fn asserting(description: &str) -> SpecDescription {
     SpecDescription {
@@ -310,6 +389,7 @@ impl<'r, T: DescriptiveSpec<'r>> AssertionFailure<'r, T> {
             spec: spec,
             expected: None,
             actual: None,
+            notes: vec![],
         }
     }
 
@@ -329,8 +409,16 @@ impl<'r, T: DescriptiveSpec<'r>> AssertionFailure<'r, T> {
         assertion
     }
 
+    /// Builder method to add a note to the panic message, shown below the expected and actual
+    /// values. Each call adds another note.
+    pub fn with_note<N: Display>(&mut self, note: N) -> &mut Self {
+        self.notes.push(note.to_string());
+
+        self
+    }
+
     /// Builds the failure message with a description (if present), the expected value,
-    /// and the actual value and then calls `panic` with the created message.
+    /// the actual value and any notes, and then calls `panic` with the created message.
     pub fn fail(&mut self) {
         if !self.expected.is_some() || !self.actual.is_some() {
             panic!("invalid assertion");
@@ -339,15 +427,17 @@ impl<'r, T: DescriptiveSpec<'r>> AssertionFailure<'r, T> {
         let location = self.maybe_build_location();
         let subject_name = self.maybe_build_subject_name();
         let description = self.maybe_build_description();
+        let notes = self.build_notes();
 
-        panic!(format!("{}{}\n\t{}expected: {}\n\t but was: {}{}\n{}",
-                       description,
-                       subject_name,
-                       TERM_RED,
-                       self.expected.clone().unwrap(),
-                       self.actual.clone().unwrap(),
-                       TERM_RESET,
-                       location))
+        panic!("{}{}\n\t{}expected: {}\n\t but was: {}{}{}\n{}",
+               description,
+               subject_name,
+               TERM_RED,
+               self.expected.clone().unwrap(),
+               self.actual.clone().unwrap(),
+               TERM_RESET,
+               notes,
+               location)
     }
 
     /// Calls `panic` with the provided message, prepending the assertion description
@@ -380,6 +470,13 @@ impl<'r, T: DescriptiveSpec<'r>> AssertionFailure<'r, T> {
         }
     }
 
+    fn build_notes(&self) -> String {
+        self.notes
+            .iter()
+            .map(|note| format!("\n\t{}note:{} {}", TERM_BOLD, TERM_RESET, note))
+            .collect()
+    }
+
     fn maybe_build_subject_name(&self) -> String {
         match self.spec.subject_name() {
             Some(value) => format!("\n\t{}for subject [{}]{}", TERM_BOLD, value, TERM_RESET),
@@ -474,6 +571,46 @@ impl<'s, S> Spec<'s, S>
         }
     }
 
+    /// Asserts that the subject is the same enum variant as the expected value, regardless of the
+    /// data either of them contains.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&Some(1)).has_same_variant_as(&Some(2));
+    /// ```
+    pub fn has_same_variant_as<E: Borrow<S>>(&mut self, other: E) {
+        let subject = self.subject;
+        let borrowed_other = other.borrow();
+
+        if mem::discriminant(subject) != mem::discriminant(borrowed_other) {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("same variant as <{:?}>", borrowed_other))
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+        }
+    }
+
+    /// Asserts that the `Debug` representations of the subject and the expected value are equal.
+    /// This is a last resort for types which don't implement `PartialEq`.
+    ///
+    /// NOTE: The comparison is only as stable as the `Debug` output. Floats in particular are
+    /// formatted exactly, so `0.1 + 0.2` won't match `0.3`, and any change to a `Debug`
+    /// implementation will change the result.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&Point { x: 1.5, y: 2.0 }).is_debug_equal_to(Point { x: 1.5, y: 2.0 });
+    /// ```
+    pub fn is_debug_equal_to<E: Borrow<S>>(&mut self, expected: E) {
+        let subject = format!("{:?}", self.subject);
+        let borrowed_expected = format!("{:?}", expected.borrow());
+
+        if subject != borrowed_expected {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("debug representation <{}>", borrowed_expected))
+                .with_actual(format!("<{}>", subject))
+                .fail();
+        }
+    }
+
     /// Transforms the subject of the `Spec` by passing it through to the provided mapping
     /// function.
     ///
@@ -498,6 +635,11 @@ mod tests {
 
     use super::prelude::*;
 
+    use super::AssertionFailure;
+    use super::checked::capture_failure;
+
+    use std::collections::HashMap;
+
     #[test]
     fn should_be_able_to_use_macro_form_with_deliberate_reference() {
         let test_vec = vec![1, 2, 3, 4, 5];
@@ -648,4 +790,160 @@ mod tests {
         pub value: u8,
     }
 
+    #[derive(Clone, Debug, PartialEq)]
+    struct Event {
+        id: u64,
+        name: String,
+        created_at: u64,
+    }
+
+    #[test]
+    fn should_not_panic_if_values_are_equal_ignoring_fields() {
+        let event = Event {
+            id: 1,
+            name: "deployed".to_owned(),
+            created_at: 1500000000,
+        };
+
+        let expected = Event {
+            id: 2,
+            name: "deployed".to_owned(),
+            created_at: 1600000000,
+        };
+
+        assert_eq_ignoring!(event, expected, [id, created_at]);
+        assert_eq_ignoring!(&event, &expected, [id, created_at,]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <Event { id: 1, name: \"rolled back\", \
+                   created_at: 0 }>\
+                   \n\t but was: <Event { id: 1, name: \"deployed\", created_at: 0 }>\
+                   \n\n\tat location: src/lib.rs:")]
+    fn should_panic_if_values_differ_in_fields_which_are_not_ignored() {
+        let event = Event {
+            id: 1,
+            name: "deployed".to_owned(),
+            created_at: 1500000000,
+        };
+
+        let expected = Event {
+            id: 1,
+            name: "rolled back".to_owned(),
+            created_at: 1600000000,
+        };
+
+        assert_eq_ignoring!(event, expected, [created_at]);
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_mutably_borrowed_vec() {
+        let mut test_vec = vec![1, 2];
+        let builder_vec: &mut Vec<i32> = &mut test_vec;
+        builder_vec.push(3);
+
+        assert_that_mut(builder_vec).has_length(3);
+        assert_that_mut(builder_vec).contains(3);
+        assert_that_mut(builder_vec).does_not_contain(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec to have length <2>\n\t but was: <3>")]
+    fn should_panic_if_mutably_borrowed_vec_does_not_have_length() {
+        let mut test_vec = vec![1, 2, 3];
+
+        assert_that_mut(&mut test_vec).has_length(2);
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum JobState {
+        Started { at: u64 },
+        Stopped { at: u64, reason: String },
+    }
+
+    #[test]
+    fn should_not_panic_if_value_has_same_variant() {
+        assert_that(&JobState::Started { at: 1 }).has_same_variant_as(JobState::Started { at: 2 });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [job]\
+                   \n\texpected: same variant as <Stopped { at: 2, reason: \"done\" }>\
+                   \n\t but was: <Started { at: 1 }>")]
+    fn should_panic_if_value_does_not_have_same_variant() {
+        assert_that(&JobState::Started { at: 1 })
+            .named("job")
+            .has_same_variant_as(JobState::Stopped {
+                at: 2,
+                reason: "done".to_string(),
+            });
+    }
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Point {
+        x: f64,
+        y: f64,
+    }
+
+    #[test]
+    fn should_not_panic_if_debug_representations_are_equal() {
+        assert_that(&Point { x: 1.5, y: 2.0 }).is_debug_equal_to(Point { x: 1.5, y: 2.0 });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: debug representation <Point { x: 0.3, y: 2.0 }>\
+                   \n\t but was: <Point { x: 0.30000000000000004, y: 2.0 }>")]
+    fn should_panic_if_debug_representations_are_not_equal() {
+        assert_that(&Point { x: 0.1 + 0.2, y: 2.0 }).is_debug_equal_to(Point { x: 0.3, y: 2.0 });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <2>\n\t but was: <1>\
+                   \n\tnote: retries are disabled in tests\
+                   \n\tnote: see the config for the default\n")]
+    fn should_append_notes_to_failure_message() {
+        let spec = assert_that(&1);
+
+        AssertionFailure::from_spec(&spec)
+            .with_expected("<2>".to_string())
+            .with_actual("<1>".to_string())
+            .with_note("retries are disabled in tests")
+            .with_note(format_args!("see the {} for the default", "config"))
+            .fail();
+    }
+
+    #[test]
+    fn empty_failures_should_share_message_format() {
+        let string_failure = capture_failure(|| assert_that(&"hello").is_empty()).unwrap();
+        let vec_failure = capture_failure(|| assert_that(&vec![1]).is_empty()).unwrap();
+        let hashmap_failure = capture_failure(|| {
+            let mut test_map = HashMap::new();
+            test_map.insert(1, 2);
+
+            assert_that(&test_map).is_empty()
+        }).unwrap();
+
+        for failure in &[string_failure, vec_failure, hashmap_failure] {
+            assert_that(failure).starts_with("\n\texpected: an empty ");
+            assert_that(failure).contains("\n\t but was: ");
+        }
+    }
+
+    #[test]
+    fn should_be_able_to_use_spec_macro_with_values_and_references() {
+        let test_vec = vec![1, 2, 3];
+
+        spec!(2).is_equal_to(2);
+        spec!(test_vec).has_length(3);
+        spec!(&test_vec).contains(2);
+    }
+
+    #[test]
+    fn should_contain_file_and_line_in_panic_for_spec_macro() {
+        let (failure, line) = (capture_failure(|| spec!(1).is_equal_to(2)).unwrap(), line!());
+
+        assert_that(&failure).ends_with(format!("\tat location: src/lib.rs:{}\n", line).as_str());
+    }
+
 }
@@ -3,9 +3,8 @@ use super::{AssertionFailure, Spec};
 use std::borrow::Borrow;
 use std::fmt::Debug;
 use std::cmp::PartialOrd;
-
-#[cfg(feature = "num")]
-use num::Float;
+use std::ops::Sub;
+use std::time::{Duration, Instant};
 
 pub trait OrderedAssertions<T>
     where T: Debug + PartialOrd
@@ -92,29 +91,102 @@ impl<'s, T> OrderedAssertions<T> for Spec<'s, T>
     }
 }
 
-#[cfg(feature = "num")]
-pub trait FloatAssertions<T: Float> {
+// The most common comparisons are also available without importing `OrderedAssertions`.
+impl<'s, T> Spec<'s, T>
+    where T: Debug + PartialOrd
+{
+    /// Asserts that the subject is less than the expected value. The subject type must
+    /// implement `PartialOrd`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&1).is_less_than(&2);
+    /// ```
+    pub fn is_less_than<E: Borrow<T>>(&mut self, other: E) {
+        OrderedAssertions::is_less_than(self, other);
+    }
+
+    /// Asserts that the subject is greater than the expected value. The subject type must
+    /// implement `PartialOrd`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&2).is_greater_than(&1);
+    /// ```
+    pub fn is_greater_than<E: Borrow<T>>(&mut self, other: E) {
+        OrderedAssertions::is_greater_than(self, other);
+    }
+}
+
+/// The magnitude of a value, used to interpret the tolerance of `is_close_to`.
+///
+/// This is implemented for the primitive numeric types and `Duration`. Implement it for your own
+/// types (such as unit wrappers like `Meters(f64)`) to be able to use `is_close_to` with them.
+pub trait Abs {
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_abs_for_signed {
+    ($($numeric_type:ty),*) => {
+        $(
+            impl Abs for $numeric_type {
+                fn abs(self) -> Self {
+                    <$numeric_type>::abs(self)
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! impl_abs_for_unsigned {
+    ($($numeric_type:ty),*) => {
+        $(
+            impl Abs for $numeric_type {
+                fn abs(self) -> Self {
+                    self
+                }
+            }
+        )*
+    }
+}
+
+impl_abs_for_signed!(i8, i16, i32, i64, isize, f32, f64);
+impl_abs_for_unsigned!(u8, u16, u32, u64, usize, Duration);
+#[cfg(feature = "time")]
+impl_abs_for_signed!(time_crate::Duration);
+
+pub trait CloseToAssertions<T> {
     fn is_close_to<E: Borrow<T>, O: Borrow<T>>(&mut self, expected: E, tolerance: O);
 }
 
-#[cfg(feature = "num")]
-impl<'s, T: Float + Debug> FloatAssertions<T> for Spec<'s, T> {
+// Kept so that existing imports continue to work now that `is_close_to` is no longer limited to
+// `Float` types.
+pub use self::CloseToAssertions as FloatAssertions;
+
+impl<'s, T> CloseToAssertions<T> for Spec<'s, T>
+    where T: Sub<Output = T> + PartialOrd + Abs + Copy + Debug
+{
     /// Asserts that the subject is close to the expected value by the specified tolerance.
-    /// The subject type must implement `Float` and `Debug`.
+    /// The subject type must implement `Sub`, `PartialOrd`, `Abs`, `Copy` and `Debug`.
+    ///
+    /// Values which can't be compared, such as `NaN`, are never close to anything.
     ///
     /// ```rust,ignore
     /// assert_that(&2.0f64).is_close_to(2.0f64, 0.01f64);
+    /// assert_that(&Duration::from_millis(105)).is_close_to(Duration::from_millis(100),
+    ///                                                      Duration::from_millis(10));
     /// ```
     fn is_close_to<E: Borrow<T>, O: Borrow<T>>(&mut self, expected: E, tolerance: O) {
         let subject = *self.subject;
         let borrowed_expected = expected.borrow();
         let borrowed_tolerance = tolerance.borrow();
 
-        let difference = (subject - *borrowed_expected).abs();
+        let is_close = match difference(subject, *borrowed_expected) {
+            Some(difference) => difference <= borrowed_tolerance.abs(),
+            None => false,
+        };
 
-        if !subject.is_finite() || difference > borrowed_tolerance.abs() {
+        if !is_close {
             AssertionFailure::from_spec(self)
-                .with_expected(format!("float close to <{:?}> (tolerance of <{:?}>)",
+                .with_expected(format!("value close to <{:?}> (tolerance of <{:?}>)",
                                        borrowed_expected,
                                        borrowed_tolerance))
                 .with_actual(format!("<{:?}>", subject))
@@ -123,12 +195,214 @@ impl<'s, T: Float + Debug> FloatAssertions<T> for Spec<'s, T> {
     }
 }
 
+/// Conversion of a primitive numeric value into an `f64`. Integers with more than 53 significant
+/// bits are rounded to the nearest `f64`.
+pub trait AsF64: Copy {
+    fn as_f64(self) -> f64;
+}
+
+macro_rules! impl_as_f64 {
+    ($($numeric_type:ty),*) => {
+        $(
+            impl AsF64 for $numeric_type {
+                fn as_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_as_f64!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+pub trait PercentageAssertions<T> {
+    fn is_within_percent_of<E: Borrow<T>>(&mut self, baseline: E, percent: f64);
+}
+
+impl<'s, T> PercentageAssertions<T> for Spec<'s, T>
+    where T: AsF64 + Debug
+{
+    /// Asserts that the subject differs from the baseline by at most the provided percentage of
+    /// the baseline. The subject type may be any primitive numeric type.
+    ///
+    /// Any change from a baseline of zero is undefined as a percentage, so only a subject of zero
+    /// is within a percentage of it.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&104.0).is_within_percent_of(100.0, 5.0);
+    /// ```
+    fn is_within_percent_of<E: Borrow<T>>(&mut self, baseline: E, percent: f64) {
+        let subject = *self.subject;
+        let borrowed_baseline = *baseline.borrow();
+        let subject_value = subject.as_f64();
+        let baseline_value = borrowed_baseline.as_f64();
+
+        let expected_message = format!("value within <{}%> of <{:?}>", percent, borrowed_baseline);
+
+        if baseline_value == 0.0 {
+            if subject_value != 0.0 {
+                AssertionFailure::from_spec(self)
+                    .with_expected(expected_message)
+                    .with_actual(format!("<{:?}> (a change from a zero baseline has no percentage)",
+                                         subject))
+                    .fail();
+            }
+
+            return;
+        }
+
+        let change = (subject_value - baseline_value) / baseline_value.abs() * 100.0;
+
+        if change.is_nan() || change.abs() > percent.abs() {
+            AssertionFailure::from_spec(self)
+                .with_expected(expected_message)
+                .with_actual(format!("<{:?}> (a change of <{:+.2}%>)", subject, change))
+                .fail();
+        }
+    }
+}
+
+pub trait AngleAssertions {
+    fn is_angularly_close_to(&mut self, expected: f64, tolerance_degrees: f64);
+}
+
+impl<'s> AngleAssertions for Spec<'s, f64> {
+    /// Asserts that the subject angle is close to the expected angle by the specified tolerance,
+    /// with all values in degrees. Angles wrap around at 360 degrees, so 359 and 1 are 2 degrees
+    /// apart.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&359.0).is_angularly_close_to(1.0, 5.0);
+    /// ```
+    fn is_angularly_close_to(&mut self, expected: f64, tolerance_degrees: f64) {
+        let subject = *self.subject;
+        let difference = (subject - expected).rem_euclid(360.0);
+        let angular_difference = difference.min(360.0 - difference);
+
+        if angular_difference.is_nan() || angular_difference > tolerance_degrees.abs() {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("angle close to <{:?}> degrees (tolerance of <{:?}> \
+                                        degrees)",
+                                       expected,
+                                       tolerance_degrees))
+                .with_actual(format!("<{:?}> (<{:?}> degrees apart)", subject, angular_difference))
+                .fail();
+        }
+    }
+}
+
+pub trait DurationAssertions {
+    fn is_close_to_secs(&mut self, expected_secs: f64, tolerance_secs: f64);
+}
+
+impl<'s> DurationAssertions for Spec<'s, Duration> {
+    /// Asserts that the subject `Duration`, in fractional seconds, is close to the expected number
+    /// of seconds by the specified tolerance.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&Duration::from_millis(1_520)).is_close_to_secs(1.5, 0.05);
+    /// ```
+    fn is_close_to_secs(&mut self, expected_secs: f64, tolerance_secs: f64) {
+        let subject_secs = self.subject.as_secs_f64();
+        let difference = (subject_secs - expected_secs).abs();
+
+        if difference.is_nan() || difference > tolerance_secs.abs() {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("duration close to <{:?}> seconds (tolerance of <{:?}> \
+                                        seconds)",
+                                       expected_secs,
+                                       tolerance_secs))
+                .with_actual(format!("<{:?}> seconds (<{:?}> seconds apart)",
+                                     subject_secs,
+                                     difference))
+                .fail();
+        }
+    }
+}
+
+pub trait InstantAssertions {
+    fn elapsed_is_under(&mut self, max: Duration);
+}
+
+impl<'s> InstantAssertions for Spec<'s, Instant> {
+    /// Asserts that the time elapsed since the subject `Instant`, measured when the assertion is
+    /// made, is under the provided maximum.
+    ///
+    /// ```rust,ignore
+    /// let start = Instant::now();
+    /// run_quick_task();
+    /// assert_that(&start).elapsed_is_under(Duration::from_millis(50));
+    /// ```
+    fn elapsed_is_under(&mut self, max: Duration) {
+        let elapsed = self.subject.elapsed();
+
+        if elapsed >= max {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("elapsed time under <{:?}>", max))
+                .with_actual(format!("<{:?}>", elapsed))
+                .fail();
+        }
+    }
+}
+
+pub trait TimestampAssertions {
+    fn is_close_to_millis(&mut self, expected_ms: i64, tolerance: Duration);
+}
+
+impl<'s> TimestampAssertions for Spec<'s, i64> {
+    /// Asserts that the subject, interpreted as a timestamp in milliseconds, is within the
+    /// specified tolerance of the expected timestamp.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&1_500_000_000_250).is_close_to_millis(1_500_000_000_000,
+    ///                                                    Duration::from_secs(1));
+    /// ```
+    fn is_close_to_millis(&mut self, expected_ms: i64, tolerance: Duration) {
+        let subject = *self.subject;
+        // Widen before subtracting so that timestamps at opposite ends of the range can't overflow.
+        let difference = (i128::from(subject) - i128::from(expected_ms)).unsigned_abs();
+
+        if difference > tolerance.as_millis() {
+            let capped_difference = difference.min(u128::from(u64::MAX)) as u64;
+
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("timestamp within <{:?}> of <{}> ms",
+                                       tolerance,
+                                       expected_ms))
+                .with_actual(format!("<{}> ms (<{}> ms apart, <{:?}>)",
+                                     subject,
+                                     difference,
+                                     Duration::from_millis(capped_difference)))
+                .fail();
+        }
+    }
+}
+
+/// Returns the absolute difference between two values, or `None` if they can't be compared.
+pub(crate) fn difference<T>(first: T, second: T) -> Option<T>
+    where T: Sub<Output = T> + PartialOrd + Copy
+{
+    // Subtract the smaller value from the larger so that unsigned types can't underflow.
+    if first >= second {
+        Some(first - second)
+    } else if first < second {
+        Some(second - first)
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::super::prelude::*;
 
+    use super::Abs;
+
     use num::Float;
+    use std::ops::Sub;
+    use std::thread;
+    use std::time::{Duration, Instant};
 
     #[test]
     fn is_less_than_should_allow_multiple_borrow_forms() {
@@ -223,30 +497,248 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "\n\texpected: float close to <1> (tolerance of <0.01>)\
-                   \n\t but was: <2>")]
+    #[should_panic(expected = "\n\texpected: value close to <1.0> (tolerance of <0.01>)\
+                   \n\t but was: <2.0>")]
     fn should_panic_if_float_is_not_close_to() {
         assert_that(&2.0f64).is_close_to(1.0f64, 0.01f64);
     }
 
     #[test]
-    #[should_panic(expected = "\n\texpected: float close to <1> (tolerance of <0.01>)\
+    #[should_panic(expected = "\n\texpected: value close to <1.0> (tolerance of <0.01>)\
                    \n\t but was: <NaN>")]
     fn should_panic_if_float_is_nan() {
         assert_that(&Float::nan()).is_close_to(1.0f64, 0.01f64);
     }
 
     #[test]
-    #[should_panic(expected = "\n\texpected: float close to <1> (tolerance of <0.01>)\
+    #[should_panic(expected = "\n\texpected: value close to <1.0> (tolerance of <0.01>)\
                    \n\t but was: <inf>")]
     fn should_panic_if_float_is_infinity() {
         assert_that(&Float::infinity()).is_close_to(1.0f64, 0.01f64);
     }
 
     #[test]
-    #[should_panic(expected = "\n\texpected: float close to <1> (tolerance of <0.01>)\
+    #[should_panic(expected = "\n\texpected: value close to <1.0> (tolerance of <0.01>)\
                    \n\t but was: <-inf>")]
     fn should_panic_if_float_is_negative_infinity() {
         assert_that(&Float::neg_infinity()).is_close_to(1.0f64, 0.01f64);
     }
+
+    #[test]
+    fn should_not_panic_if_integer_is_close_to() {
+        assert_that(&10u8).is_close_to(12u8, 2u8);
+        assert_that(&-10i32).is_close_to(-12i32, 2i32);
+        assert_that(&-10i32).is_close_to(-8i32, -2i32);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value close to <13> (tolerance of <2>)\
+                   \n\t but was: <10>")]
+    fn should_panic_if_unsigned_integer_is_not_close_to() {
+        assert_that(&10u8).is_close_to(13u8, 2u8);
+    }
+
+    #[test]
+    fn should_not_panic_if_duration_is_close_to() {
+        assert_that(&Duration::from_millis(95))
+            .is_close_to(Duration::from_millis(100), Duration::from_millis(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value close to <100ms> (tolerance of <10ms>)\
+                   \n\t but was: <111ms>")]
+    fn should_panic_if_duration_is_not_close_to() {
+        assert_that(&Duration::from_millis(111))
+            .is_close_to(Duration::from_millis(100), Duration::from_millis(10));
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    struct Meters(f64);
+
+    impl Sub for Meters {
+        type Output = Meters;
+
+        fn sub(self, other: Meters) -> Meters {
+            Meters(self.0 - other.0)
+        }
+    }
+
+    impl Abs for Meters {
+        fn abs(self) -> Meters {
+            Meters(self.0.abs())
+        }
+    }
+
+    #[test]
+    fn should_not_panic_if_custom_unit_is_close_to() {
+        assert_that(&Meters(2.0)).is_close_to(Meters(2.5), Meters(0.5));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value close to <Meters(2.5)> \
+                   (tolerance of <Meters(0.1)>)\n\t but was: <Meters(2.0)>")]
+    fn should_panic_if_custom_unit_is_not_close_to() {
+        assert_that(&Meters(2.0)).is_close_to(Meters(2.5), Meters(0.1));
+    }
+
+    #[test]
+    fn should_not_panic_if_value_is_within_percent_of_baseline() {
+        assert_that(&104.0).is_within_percent_of(100.0, 5.0);
+        assert_that(&95u32).is_within_percent_of(100, 5.0);
+        assert_that(&-10.4f32).is_within_percent_of(-10.0, 5.0);
+    }
+
+    #[test]
+    fn should_not_panic_if_wide_integer_is_within_percent_of_baseline() {
+        assert_that(&1_030_000_000i64).is_within_percent_of(1_000_000_000, 5.0);
+        assert_that(&2_000_000_000u64).is_within_percent_of(2_050_000_000, 5.0);
+        assert_that(&4_096usize).is_within_percent_of(4_000, 5.0);
+        assert_that(&-1_000_000i128).is_within_percent_of(-1_020_000, 5.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value within <5%> of <1000>\
+                   \n\t but was: <1100> (a change of <+10.00%>)")]
+    fn should_panic_if_usize_is_not_within_percent_of_baseline() {
+        assert_that(&1_100usize).is_within_percent_of(1_000, 5.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [response time]\
+                   \n\texpected: value within <5%> of <200.0>\
+                   \n\t but was: <215.0> (a change of <+7.50%>)")]
+    fn should_panic_if_value_regressed_beyond_percent_of_baseline() {
+        assert_that(&215.0).named("response time").is_within_percent_of(200.0, 5.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value within <5%> of <-10>\
+                   \n\t but was: <-8> (a change of <+20.00%>)")]
+    fn should_panic_if_value_is_not_within_percent_of_negative_baseline() {
+        assert_that(&-8).is_within_percent_of(-10, 5.0);
+    }
+
+    #[test]
+    fn should_not_panic_if_zero_is_within_percent_of_zero_baseline() {
+        assert_that(&0.0).is_within_percent_of(0.0, 5.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value within <5%> of <0.0>\
+                   \n\t but was: <0.5> (a change from a zero baseline has no percentage)")]
+    fn should_panic_if_value_is_compared_with_zero_baseline() {
+        assert_that(&0.5).is_within_percent_of(0.0, 5.0);
+    }
+
+    #[test]
+    fn should_not_panic_if_angle_is_close_to_expected_across_wraparound() {
+        assert_that(&359.0).is_angularly_close_to(1.0, 2.0);
+        assert_that(&1.0).is_angularly_close_to(359.0, 2.0);
+        assert_that(&-90.0).is_angularly_close_to(270.0, 0.5);
+        assert_that(&45.0).is_angularly_close_to(46.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: angle close to <5.0> degrees (tolerance of <5.0> \
+                   degrees)\n\t but was: <355.0> (<10.0> degrees apart)")]
+    fn should_panic_if_angle_is_not_close_to_expected_across_wraparound() {
+        assert_that(&355.0).is_angularly_close_to(5.0, 5.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: angle close to <0.0> degrees (tolerance of <1.0> \
+                   degrees)\n\t but was: <NaN> (<NaN> degrees apart)")]
+    fn should_panic_if_angle_is_nan() {
+        assert_that(&f64::NAN).is_angularly_close_to(0.0, 1.0);
+    }
+
+    mod without_ordered_assertions_import {
+
+        use super::super::super::assert_that;
+
+        #[test]
+        fn should_not_panic_if_value_is_greater_than_or_less_than_expected() {
+            assert_that(&2).is_greater_than(1);
+            assert_that(&1.5).is_less_than(2.0);
+        }
+
+        #[test]
+        #[should_panic(expected = "\n\texpected: value greater than <3>\n\t but was: <2>")]
+        fn should_panic_if_value_is_not_greater_than_expected() {
+            assert_that(&2).is_greater_than(3);
+        }
+
+        #[test]
+        #[should_panic(expected = "\n\texpected: value less than <1>\n\t but was: <2>")]
+        fn should_panic_if_value_is_not_less_than_expected() {
+            assert_that(&2).is_less_than(1);
+        }
+
+    }
+
+    #[test]
+    fn should_not_panic_if_timestamp_is_within_tolerance() {
+        assert_that(&1_500_000_000_250i64)
+            .is_close_to_millis(1_500_000_000_000, Duration::from_secs(1));
+        assert_that(&999i64).is_close_to_millis(1_000, Duration::from_millis(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: timestamp within <1s> of <1500000000000> ms\
+                   \n\t but was: <1500000001500> ms (<1500> ms apart, <1.5s>)")]
+    fn should_panic_if_timestamp_is_beyond_tolerance() {
+        assert_that(&1_500_000_001_500i64)
+            .is_close_to_millis(1_500_000_000_000, Duration::from_secs(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [created_at]\
+                   \n\texpected: timestamp within <0ns> of <-9223372036854775808> ms\
+                   \n\t but was: <9223372036854775807> ms (<18446744073709551615> ms apart")]
+    fn should_panic_without_overflowing_if_timestamps_are_at_range_extremes() {
+        assert_that(&i64::MAX).named("created_at").is_close_to_millis(i64::MIN, Duration::ZERO);
+    }
+
+    #[test]
+    fn should_not_panic_if_duration_is_close_to_secs() {
+        assert_that(&Duration::from_millis(1_520)).is_close_to_secs(1.5, 0.05);
+        assert_that(&Duration::from_secs(2)).is_close_to_secs(2.0, 0.0);
+    }
+
+    #[test]
+    fn should_not_panic_if_sub_millisecond_duration_is_close_to_secs() {
+        assert_that(&Duration::from_micros(250)).is_close_to_secs(0.0003, 0.0001);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: duration close to <0.0003> seconds (tolerance of \
+                   <1e-5> seconds)\n\t but was: <0.00025> seconds")]
+    fn should_panic_if_sub_millisecond_duration_is_not_close_to_secs() {
+        assert_that(&Duration::from_micros(250)).is_close_to_secs(0.0003, 0.00001);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [request time]\
+                   \n\texpected: duration close to <1.5> seconds (tolerance of <0.25> seconds)\
+                   \n\t but was: <2.0> seconds (<0.5> seconds apart)")]
+    fn should_panic_if_duration_is_not_close_to_secs() {
+        assert_that(&Duration::from_secs(2)).named("request time").is_close_to_secs(1.5, 0.25);
+    }
+
+    #[test]
+    fn should_not_panic_if_elapsed_time_is_under_max() {
+        let start = Instant::now();
+        thread::sleep(Duration::from_millis(1));
+
+        assert_that(&start).elapsed_is_under(Duration::from_secs(60));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [task]\n\texpected: elapsed time under <5ms>")]
+    fn should_panic_if_elapsed_time_is_not_under_max() {
+        let start = Instant::now();
+        thread::sleep(Duration::from_millis(20));
+
+        assert_that(&start).named("task").elapsed_is_under(Duration::from_millis(5));
+    }
 }
@@ -9,6 +9,9 @@ pub trait OptionAssertions<'r, T>
 {
     fn is_some(&mut self) -> Spec<'r, T>;
     fn is_none(&mut self);
+    fn is_some_with_value_matching<F>(&mut self, predicate: F, description: &str)
+        where F: Fn(&T) -> bool;
+    fn exactly_one_is_some(&mut self, other: &Option<T>);
 }
 
 pub trait ContainingOptionAssertions<T>
@@ -96,6 +99,53 @@ impl<'s, T> OptionAssertions<'s, T> for Spec<'s, Option<T>>
             }
         }
     }
+
+    /// Asserts that the subject is a `Some` containing a value which matches the provided
+    /// predicate. The description is included in the failure message to explain what the value
+    /// was expected to satisfy.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&Some(4)).is_some_with_value_matching(|val| val % 2 == 0, "an even number");
+    /// ```
+    fn is_some_with_value_matching<F>(&mut self, predicate: F, description: &str)
+        where F: Fn(&T) -> bool
+    {
+        let actual = match *self.subject {
+            Some(ref val) if predicate(val) => return,
+            Some(ref val) => format!("option<{:?}>", val),
+            None => "option[none]".to_string(),
+        };
+
+        AssertionFailure::from_spec(self)
+            .with_expected(format!("option[some] with value matching <{}>", description))
+            .with_actual(actual)
+            .fail();
+    }
+
+    /// Asserts that exactly one of the subject and the other option is `Some`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&Some(1)).exactly_one_is_some(&None);
+    /// ```
+    fn exactly_one_is_some(&mut self, other: &Option<T>) {
+        if self.subject.is_some() != other.is_some() {
+            return;
+        }
+
+        AssertionFailure::from_spec(self)
+            .with_expected("exactly one of subject and other to be option[some]".to_string())
+            .with_actual(format!("subject {} and other {}",
+                                 describe_option(self.subject),
+                                 describe_option(other)))
+            .fail();
+    }
+}
+
+fn describe_option<T: Debug>(option: &Option<T>) -> String {
+    match *option {
+        Some(ref val) => format!("option<{:?}>", val),
+        None => "option[none]".to_string(),
+    }
 }
 
 #[cfg(test)]
@@ -163,4 +213,48 @@ mod tests {
         assert_that(&option).is_none();
     }
 
+    #[test]
+    fn should_not_panic_if_option_contains_value_matching_predicate() {
+        let option = Some(4);
+        assert_that(&option).is_some_with_value_matching(|val| val % 2 == 0, "an even number");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: option[some] with value matching <an even number>\
+                   \n\t but was: option<3>")]
+    fn should_panic_if_option_contains_value_not_matching_predicate() {
+        let option = Some(3);
+        assert_that(&option).is_some_with_value_matching(|val| val % 2 == 0, "an even number");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: option[some] with value matching <an even number>\
+                   \n\t but was: option[none]")]
+    fn should_panic_if_option_is_none_but_expected_value_matching_predicate() {
+        let option: Option<u8> = None;
+        assert_that(&option).is_some_with_value_matching(|val| val % 2 == 0, "an even number");
+    }
+
+    #[test]
+    fn should_not_panic_if_exactly_one_option_is_some() {
+        assert_that(&Some(1)).exactly_one_is_some(&None);
+        assert_that(&None).exactly_one_is_some(&Some("token"));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: exactly one of subject and other to be option[some]\
+                   \n\t but was: subject option<\"password\"> and other option<\"token\">")]
+    fn should_panic_if_both_options_are_some() {
+        assert_that(&Some("password")).exactly_one_is_some(&Some("token"));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [password]\
+                   \n\texpected: exactly one of subject and other to be option[some]\
+                   \n\t but was: subject option[none] and other option[none]")]
+    fn should_panic_if_both_options_are_none() {
+        let option: Option<&str> = None;
+        assert_that(&option).named("password").exactly_one_is_some(&None);
+    }
+
 }
@@ -1,6 +1,7 @@
 use super::{AssertionFailure, DescriptiveSpec, Spec};
 
 use std::borrow::Borrow;
+use std::fs;
 use std::path::{Path, PathBuf};
 
 pub trait PathAssertions {
@@ -9,6 +10,15 @@ pub trait PathAssertions {
     fn is_a_file(&mut self);
     fn is_a_directory(&mut self);
     fn has_file_name<'r, E: Borrow<&'r str>>(&mut self, expected_file_name: E);
+    fn is_canonically_equal_to<E: Borrow<Path>>(&mut self, expected: E);
+    fn has_extension<'r, E: Borrow<&'r str>>(&mut self, expected_extension: E);
+    fn is_ancestor_of<E: Borrow<Path>>(&mut self, descendant: E);
+    fn starts_with_path<E: Borrow<Path>>(&mut self, prefix: E);
+    fn read_to_string_and_assert<F: FnOnce(Spec<String>)>(&mut self, assertions: F);
+    fn has_same_contents_as<E: Borrow<Path>>(&mut self, other: E);
+    fn is_empty_file(&mut self);
+    fn has_file_size(&mut self, bytes: u64);
+    fn has_file_size_at_least(&mut self, bytes: u64);
 }
 
 
@@ -57,6 +67,98 @@ impl<'s> PathAssertions for Spec<'s, &'s Path> {
     fn has_file_name<'r, E: Borrow<&'r str>>(&mut self, expected_file_name: E) {
         has_file_name(self.subject, expected_file_name.borrow(), self)
     }
+
+    /// Asserts that the subject `Path` and the expected path refer to the same location once
+    /// both have been canonicalized. Both paths must exist.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&Path::new("/tmp/dir/../file")).is_canonically_equal_to(Path::new("/tmp/file"));
+    /// ```
+    fn is_canonically_equal_to<E: Borrow<Path>>(&mut self, expected: E) {
+        is_canonically_equal_to(self.subject, expected.borrow(), self)
+    }
+
+    /// Asserts that the subject `Path` has the expected extension.
+    ///
+    /// Only the part after the final `.` is compared, so a path of `archive.tar.gz` has an
+    /// extension of `gz`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&Path::new("/tmp/file.txt")).has_extension(&"txt");
+    /// ```
+    fn has_extension<'r, E: Borrow<&'r str>>(&mut self, expected_extension: E) {
+        has_extension(self.subject, expected_extension.borrow(), self)
+    }
+
+    /// Asserts that the subject `Path` is an ancestor of the provided path. Paths are compared
+    /// by their components rather than as strings, and a path is not an ancestor of itself.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&Path::new("/tmp")).is_ancestor_of(Path::new("/tmp/dir/file"));
+    /// ```
+    fn is_ancestor_of<E: Borrow<Path>>(&mut self, descendant: E) {
+        is_ancestor_of(self.subject, descendant.borrow(), self)
+    }
+
+    /// Asserts that the subject `Path` starts with the provided path. Paths are compared by
+    /// their components rather than as strings, so `/a/bc` does not start with `/a/b`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&Path::new("/tmp/dir/file")).starts_with_path(Path::new("/tmp"));
+    /// ```
+    fn starts_with_path<E: Borrow<Path>>(&mut self, prefix: E) {
+        starts_with_path(self.subject, prefix.borrow(), self)
+    }
+
+    /// Reads the file referred to by the subject `Path` into a `String`, then passes a `Spec`
+    /// over its contents to the provided assertions. Fails if the file cannot be read.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&Path::new("/tmp/file"))
+    ///     .read_to_string_and_assert(|mut contents| contents.contains("expected"));
+    /// ```
+    fn read_to_string_and_assert<F: FnOnce(Spec<String>)>(&mut self, assertions: F) {
+        read_to_string_and_assert(self.subject, self, assertions)
+    }
+
+    /// Asserts that the file referred to by the subject `Path` has exactly the same contents
+    /// as the file referred to by the provided path. Both files must be readable.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&Path::new("/tmp/output")).has_same_contents_as(Path::new("/tmp/golden"));
+    /// ```
+    fn has_same_contents_as<E: Borrow<Path>>(&mut self, other: E) {
+        has_same_contents_as(self.subject, other.borrow(), self)
+    }
+
+    /// Asserts that the subject `Path` refers to an existing file which is empty.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&Path::new("/tmp/file")).is_empty_file();
+    /// ```
+    fn is_empty_file(&mut self) {
+        is_empty_file(self.subject, self)
+    }
+
+    /// Asserts that the subject `Path` refers to an existing file of exactly the provided
+    /// size in bytes.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&Path::new("/tmp/file")).has_file_size(1024);
+    /// ```
+    fn has_file_size(&mut self, bytes: u64) {
+        has_file_size(self.subject, bytes, self)
+    }
+
+    /// Asserts that the subject `Path` refers to an existing file of at least the provided
+    /// size in bytes.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&Path::new("/tmp/file")).has_file_size_at_least(1);
+    /// ```
+    fn has_file_size_at_least(&mut self, bytes: u64) {
+        has_file_size_at_least(self.subject, bytes, self)
+    }
 }
 
 impl<'s> PathAssertions for Spec<'s, PathBuf> {
@@ -104,6 +206,99 @@ impl<'s> PathAssertions for Spec<'s, PathBuf> {
     fn has_file_name<'r, E: Borrow<&'r str>>(&mut self, expected_file_name: E) {
         has_file_name(self.subject.as_path(), expected_file_name.borrow(), self)
     }
+
+    /// Asserts that the subject `PathBuf` and the expected path refer to the same location once
+    /// both have been canonicalized. Both paths must exist.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&PathBuf::from("/tmp/dir/../file"))
+    ///     .is_canonically_equal_to(Path::new("/tmp/file"));
+    /// ```
+    fn is_canonically_equal_to<E: Borrow<Path>>(&mut self, expected: E) {
+        is_canonically_equal_to(self.subject.as_path(), expected.borrow(), self)
+    }
+
+    /// Asserts that the subject `PathBuf` has the expected extension.
+    ///
+    /// Only the part after the final `.` is compared, so a path of `archive.tar.gz` has an
+    /// extension of `gz`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&PathBuf::from("/tmp/file.txt")).has_extension(&"txt");
+    /// ```
+    fn has_extension<'r, E: Borrow<&'r str>>(&mut self, expected_extension: E) {
+        has_extension(self.subject.as_path(), expected_extension.borrow(), self)
+    }
+
+    /// Asserts that the subject `PathBuf` is an ancestor of the provided path. Paths are compared
+    /// by their components rather than as strings, and a path is not an ancestor of itself.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&PathBuf::from("/tmp")).is_ancestor_of(Path::new("/tmp/dir/file"));
+    /// ```
+    fn is_ancestor_of<E: Borrow<Path>>(&mut self, descendant: E) {
+        is_ancestor_of(self.subject.as_path(), descendant.borrow(), self)
+    }
+
+    /// Asserts that the subject `PathBuf` starts with the provided path. Paths are compared by
+    /// their components rather than as strings, so `/a/bc` does not start with `/a/b`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&PathBuf::from("/tmp/dir/file")).starts_with_path(Path::new("/tmp"));
+    /// ```
+    fn starts_with_path<E: Borrow<Path>>(&mut self, prefix: E) {
+        starts_with_path(self.subject.as_path(), prefix.borrow(), self)
+    }
+
+    /// Reads the file referred to by the subject `PathBuf` into a `String`, then passes a `Spec`
+    /// over its contents to the provided assertions. Fails if the file cannot be read.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&PathBuf::from("/tmp/file"))
+    ///     .read_to_string_and_assert(|mut contents| contents.contains("expected"));
+    /// ```
+    fn read_to_string_and_assert<F: FnOnce(Spec<String>)>(&mut self, assertions: F) {
+        read_to_string_and_assert(self.subject.as_path(), self, assertions)
+    }
+
+    /// Asserts that the file referred to by the subject `PathBuf` has exactly the same contents
+    /// as the file referred to by the provided path. Both files must be readable.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&PathBuf::from("/tmp/output")).has_same_contents_as(Path::new("/tmp/golden"));
+    /// ```
+    fn has_same_contents_as<E: Borrow<Path>>(&mut self, other: E) {
+        has_same_contents_as(self.subject.as_path(), other.borrow(), self)
+    }
+
+    /// Asserts that the subject `PathBuf` refers to an existing file which is empty.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&PathBuf::from("/tmp/file")).is_empty_file();
+    /// ```
+    fn is_empty_file(&mut self) {
+        is_empty_file(self.subject.as_path(), self)
+    }
+
+    /// Asserts that the subject `PathBuf` refers to an existing file of exactly the provided
+    /// size in bytes.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&PathBuf::from("/tmp/file")).has_file_size(1024);
+    /// ```
+    fn has_file_size(&mut self, bytes: u64) {
+        has_file_size(self.subject.as_path(), bytes, self)
+    }
+
+    /// Asserts that the subject `PathBuf` refers to an existing file of at least the provided
+    /// size in bytes.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&PathBuf::from("/tmp/file")).has_file_size_at_least(1);
+    /// ```
+    fn has_file_size_at_least(&mut self, bytes: u64) {
+        has_file_size_at_least(self.subject.as_path(), bytes, self)
+    }
 }
 
 fn exists<'s, S: DescriptiveSpec<'s>>(subject: &Path, spec: &'s S) {
@@ -171,6 +366,209 @@ fn has_file_name<'s, S: DescriptiveSpec<'s>>(subject: &Path,
     }
 }
 
+fn is_canonically_equal_to<'s, S: DescriptiveSpec<'s>>(subject: &Path,
+                                                       expected: &Path,
+                                                       spec: &'s S) {
+    let canonical_expected = match expected.canonicalize() {
+        Ok(path) => path,
+        Err(err) => {
+            AssertionFailure::from_spec(spec)
+                .fail_with_message(format!("expected Path <{:?}> could not be canonicalized: {}",
+                                           expected,
+                                           err));
+            unreachable!();
+        }
+    };
+
+    let canonical_subject = match subject.canonicalize() {
+        Ok(path) => path,
+        Err(err) => {
+            AssertionFailure::from_spec(spec)
+                .with_expected(format!("Path canonically equal to <{:?}>", canonical_expected))
+                .with_actual(format!("a Path of <{:?}> which could not be canonicalized: {}",
+                                     subject,
+                                     err))
+                .fail();
+            unreachable!();
+        }
+    };
+
+    if canonical_subject != canonical_expected {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("Path canonically equal to <{:?}>", canonical_expected))
+            .with_actual(format!("<{:?}>", canonical_subject))
+            .fail();
+    }
+}
+
+fn has_extension<'s, S: DescriptiveSpec<'s>>(subject: &Path,
+                                             expected_extension: &str,
+                                             spec: &'s S) {
+    let actual = match subject.extension() {
+        Some(os_string) => {
+            match os_string.to_str() {
+                Some(val) if val.eq(expected_extension) => return,
+                Some(val) => format!("<{}>", val),
+                None => "an invalid UTF-8 extension".to_string(),
+            }
+        }
+        None => format!("a Path of <{:?}> without an extension", subject),
+    };
+
+    AssertionFailure::from_spec(spec)
+        .with_expected(format!("Path with extension of <{}>", expected_extension))
+        .with_actual(actual)
+        .fail();
+}
+
+fn is_ancestor_of<'s, S: DescriptiveSpec<'s>>(subject: &Path, descendant: &Path, spec: &'s S) {
+    if subject == descendant || !descendant.starts_with(subject) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("Path of <{:?}> to be an ancestor of <{:?}>",
+                                   subject,
+                                   descendant))
+            .with_actual("not an ancestor".to_string())
+            .fail();
+    }
+}
+
+fn starts_with_path<'s, S: DescriptiveSpec<'s>>(subject: &Path, prefix: &Path, spec: &'s S) {
+    if !subject.starts_with(prefix) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("Path starting with <{:?}>", prefix))
+            .with_actual(format!("<{:?}>", subject))
+            .fail();
+    }
+}
+
+fn read_to_string_and_assert<'s, S, F>(subject: &Path, spec: &'s S, assertions: F)
+    where S: DescriptiveSpec<'s>,
+          F: FnOnce(Spec<String>)
+{
+    match fs::read_to_string(subject) {
+        Ok(contents) => {
+            assertions(Spec {
+                subject: &contents,
+                subject_name: spec.subject_name(),
+                location: spec.location(),
+                description: spec.description(),
+            })
+        }
+        Err(err) => {
+            AssertionFailure::from_spec(spec)
+                .with_expected(format!("Path of <{:?}> to be readable as a string", subject))
+                .with_actual(format!("an error of <{}>", err))
+                .fail();
+        }
+    }
+}
+
+fn has_same_contents_as<'s, S: DescriptiveSpec<'s>>(subject: &Path, other: &Path, spec: &'s S) {
+    let expected_contents = match fs::read(other) {
+        Ok(contents) => contents,
+        Err(err) => {
+            AssertionFailure::from_spec(spec)
+                .fail_with_message(format!("expected Path <{:?}> could not be read: {}",
+                                           other,
+                                           err));
+            unreachable!();
+        }
+    };
+
+    let subject_contents = match fs::read(subject) {
+        Ok(contents) => contents,
+        Err(err) => {
+            AssertionFailure::from_spec(spec)
+                .with_expected(format!("Path with same contents as <{:?}>", other))
+                .with_actual(format!("a Path of <{:?}> which could not be read: {}", subject, err))
+                .fail();
+            unreachable!();
+        }
+    };
+
+    if subject_contents == expected_contents {
+        return;
+    }
+
+    let offset = subject_contents.iter()
+        .zip(expected_contents.iter())
+        .take_while(|&(actual, expected)| actual == expected)
+        .count();
+
+    AssertionFailure::from_spec(spec)
+        .with_expected(format!("Path with same contents as <{:?}> (bytes <{}>)",
+                               other,
+                               build_hex_window(&expected_contents, offset)))
+        .with_actual(format!("contents differing at byte offset <{}> (bytes <{}>)",
+                             offset,
+                             build_hex_window(&subject_contents, offset)))
+        .fail();
+}
+
+fn build_hex_window(contents: &[u8], offset: usize) -> String {
+    let start = offset.saturating_sub(4);
+    let end = contents.len().min(offset + 4);
+
+    contents.get(start..end)
+        .unwrap_or(&[])
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn is_empty_file<'s, S: DescriptiveSpec<'s>>(subject: &Path, spec: &'s S) {
+    let file_size = file_size(subject, spec);
+
+    if file_size != 0 {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("Path of <{:?}> to be an empty file", subject))
+            .with_actual(format!("a file of <{}> bytes", file_size))
+            .fail();
+    }
+}
+
+fn has_file_size<'s, S: DescriptiveSpec<'s>>(subject: &Path, bytes: u64, spec: &'s S) {
+    let file_size = file_size(subject, spec);
+
+    if file_size != bytes {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("Path of <{:?}> to have file size of <{}> bytes",
+                                   subject,
+                                   bytes))
+            .with_actual(format!("<{}> bytes", file_size))
+            .fail();
+    }
+}
+
+fn has_file_size_at_least<'s, S: DescriptiveSpec<'s>>(subject: &Path, bytes: u64, spec: &'s S) {
+    let file_size = file_size(subject, spec);
+
+    if file_size < bytes {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("Path of <{:?}> to have file size of at least <{}> bytes",
+                                   subject,
+                                   bytes))
+            .with_actual(format!("<{}> bytes", file_size))
+            .fail();
+    }
+}
+
+fn file_size<'s, S: DescriptiveSpec<'s>>(subject: &Path, spec: &'s S) -> u64 {
+    let actual = match fs::metadata(subject) {
+        Ok(ref metadata) if metadata.is_file() => return metadata.len(),
+        Ok(_) => "not a resolvable file".to_string(),
+        Err(_) => "a non-existent Path".to_string(),
+    };
+
+    AssertionFailure::from_spec(spec)
+        .with_expected(format!("Path of <{:?}> to be a file", subject))
+        .with_actual(actual)
+        .fail();
+
+    unreachable!();
+}
+
 fn fail_from_file_name<'s, S: DescriptiveSpec<'s>>(spec: &'s S, expected: &str, actual: String) {
     AssertionFailure::from_spec(spec)
         .with_expected(build_file_name_message(expected))
@@ -187,10 +585,42 @@ mod tests {
 
     use super::super::prelude::*;
 
+    use std::env;
+    use std::fs;
+    use std::ops::Deref;
     use std::path::{Path, PathBuf};
+    use std::process;
 
     static MANIFEST_PATH: &'static str = env!("CARGO_MANIFEST_DIR");
 
+    /// A temporary directory which is removed when dropped, so that it is also cleaned up when
+    /// the test panics.
+    struct TempDir {
+        path: PathBuf,
+    }
+
+    impl TempDir {
+        fn new(name: &str) -> TempDir {
+            let path = env::temp_dir().join(format!("spectral-{}-{}", process::id(), name));
+            fs::create_dir_all(&path).unwrap();
+            TempDir { path }
+        }
+    }
+
+    impl Deref for TempDir {
+        type Target = Path;
+
+        fn deref(&self) -> &Path {
+            &self.path
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+
     #[test]
     pub fn should_not_panic_if_path_exists() {
         assert_that(&Path::new(MANIFEST_PATH)).exists();
@@ -354,4 +784,243 @@ mod tests {
         let path = MANIFEST_PATH.to_string() + "/..";
         assert_that(&PathBuf::from(&path)).has_file_name(&"pom.xml");
     }
+
+    #[test]
+    pub fn should_not_panic_if_paths_are_canonically_equal() {
+        let dir = TempDir::new("canonically-equal");
+        fs::create_dir_all(dir.join("a/b")).unwrap();
+
+        assert_that(&dir.join("a/./b/../b").as_path()).is_canonically_equal_to(dir.join("a/b"));
+        assert_that(&dir.join("a/b/..")).is_canonically_equal_to(dir.join("a"));
+    }
+
+    #[test]
+    #[should_panic(expected = "-canonically-different/b\">")]
+    pub fn should_panic_if_paths_are_not_canonically_equal() {
+        let dir = TempDir::new("canonically-different");
+        fs::create_dir_all(dir.join("a")).unwrap();
+        fs::create_dir_all(dir.join("b")).unwrap();
+
+        assert_that(&dir.join("b/../a")).is_canonically_equal_to(dir.join("a/../b"));
+    }
+
+    #[test]
+    #[should_panic(expected = "which could not be canonicalized")]
+    pub fn should_panic_if_subject_path_cannot_be_canonicalized() {
+        let failing_path = MANIFEST_PATH.to_string() + "/does-not-exist";
+        assert_that(&PathBuf::from(&failing_path))
+            .is_canonically_equal_to(Path::new(MANIFEST_PATH));
+    }
+
+    #[test]
+    #[should_panic(expected = "/does-not-exist\"> could not be canonicalized")]
+    pub fn should_panic_if_expected_path_cannot_be_canonicalized() {
+        let failing_path = MANIFEST_PATH.to_string() + "/does-not-exist";
+        assert_that(&PathBuf::from(MANIFEST_PATH))
+            .is_canonically_equal_to(Path::new(&failing_path));
+    }
+
+    #[test]
+    pub fn should_not_panic_if_path_has_correct_extension() {
+        let path = MANIFEST_PATH.to_string() + "/Cargo.toml";
+        assert_that(&Path::new(&path)).has_extension("toml");
+    }
+
+    #[test]
+    pub fn should_only_compare_final_extension_of_path() {
+        assert_that(&Path::new("archive.tar.gz")).has_extension("gz");
+        assert_that(&PathBuf::from("archive.tar.gz")).has_extension("gz");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: Path with extension of <xml>\n\t but was: <toml>")]
+    pub fn should_panic_if_path_does_not_have_correct_extension() {
+        let path = MANIFEST_PATH.to_string() + "/Cargo.toml";
+        assert_that(&Path::new(&path)).has_extension("xml");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: Path with extension of <txt>\
+                   \n\t but was: a Path of <\"LICENSE\"> without an extension")]
+    pub fn should_panic_if_path_does_not_have_an_extension() {
+        assert_that(&Path::new("LICENSE")).has_extension("txt");
+    }
+
+    #[test]
+    pub fn should_not_panic_if_pathbuf_has_correct_extension() {
+        let path = MANIFEST_PATH.to_string() + "/Cargo.toml";
+        assert_that(&PathBuf::from(&path)).has_extension("toml");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: Path with extension of <xml>\n\t but was: <toml>")]
+    pub fn should_panic_if_pathbuf_does_not_have_correct_extension() {
+        let path = MANIFEST_PATH.to_string() + "/Cargo.toml";
+        assert_that(&PathBuf::from(&path)).has_extension("xml");
+    }
+
+    #[test]
+    pub fn should_not_panic_if_path_is_ancestor_of_descendant() {
+        assert_that(&Path::new("/a/b")).is_ancestor_of(Path::new("/a/b/c"));
+        assert_that(&PathBuf::from("/a")).is_ancestor_of(Path::new("/a/b/c"));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: Path of <\"/a/b\"> to be an ancestor of <\"/a/c\">\
+                   \n\t but was: not an ancestor")]
+    pub fn should_panic_if_path_is_not_ancestor_of_descendant() {
+        assert_that(&Path::new("/a/b")).is_ancestor_of(Path::new("/a/c"));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: Path of <\"/a/b\"> to be an ancestor of <\"/a/b\">")]
+    pub fn should_panic_if_path_is_expected_to_be_ancestor_of_itself() {
+        assert_that(&PathBuf::from("/a/b")).is_ancestor_of(Path::new("/a/b"));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: Path of <\"/a/b\"> to be an ancestor of <\"/a/bc\">")]
+    pub fn should_panic_if_path_only_shares_string_prefix_with_descendant() {
+        assert_that(&Path::new("/a/b")).is_ancestor_of(Path::new("/a/bc"));
+    }
+
+    #[test]
+    pub fn should_not_panic_if_path_starts_with_prefix() {
+        assert_that(&Path::new("/a/b/c")).starts_with_path(Path::new("/a/b"));
+        assert_that(&PathBuf::from("/a/b/c")).starts_with_path(PathBuf::from("/a/b/c"));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: Path starting with <\"/a/b\">\
+                   \n\t but was: <\"/a/bc\">")]
+    pub fn should_panic_if_path_only_shares_string_prefix() {
+        assert_that(&PathBuf::from("/a/bc")).starts_with_path(Path::new("/a/b"));
+    }
+
+    #[test]
+    pub fn should_be_able_to_assert_on_contents_of_path() {
+        let path = MANIFEST_PATH.to_string() + "/Cargo.toml";
+        assert_that(&Path::new(&path))
+            .read_to_string_and_assert(|mut contents| contents.contains("[package]"));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string containing <\"[workspace]\">")]
+    pub fn should_panic_if_contents_of_path_do_not_satisfy_assertions() {
+        let path = MANIFEST_PATH.to_string() + "/Cargo.toml";
+        assert_that(&PathBuf::from(&path))
+            .read_to_string_and_assert(|mut contents| contents.contains("[workspace]"));
+    }
+
+    #[test]
+    #[should_panic(expected = "/does-not-exist\"> to be readable as a string\
+                   \n\t but was: an error of <No such file or directory")]
+    pub fn should_panic_if_path_cannot_be_read_to_string() {
+        let failing_path = MANIFEST_PATH.to_string() + "/does-not-exist";
+        assert_that(&PathBuf::from(&failing_path))
+            .read_to_string_and_assert(|mut contents| contents.is_empty());
+    }
+
+    #[test]
+    pub fn should_not_panic_if_paths_have_same_contents() {
+        let dir = TempDir::new("same-contents");
+        fs::write(dir.join("actual"), "Hello World").unwrap();
+        fs::write(dir.join("expected"), "Hello World").unwrap();
+
+        assert_that(&dir.join("actual")).has_same_contents_as(dir.join("expected"));
+        assert_that(&dir.join("actual").as_path()).has_same_contents_as(dir.join("expected"));
+    }
+
+    #[test]
+    #[should_panic(expected = "-different-contents/expected\"> (bytes <6c 6c 6f 20 77 6f 72 6c>)\
+                   \n\t but was: contents differing at byte offset <6> \
+                   (bytes <6c 6c 6f 20 57 6f 72 6c>)")]
+    pub fn should_panic_if_paths_have_different_contents() {
+        let dir = TempDir::new("different-contents");
+        fs::write(dir.join("actual"), "Hello World").unwrap();
+        fs::write(dir.join("expected"), "Hello world").unwrap();
+
+        assert_that(&dir.join("actual")).has_same_contents_as(dir.join("expected"));
+    }
+
+    #[test]
+    #[should_panic(expected = "contents differing at byte offset <5> (bytes <65 6c 6c 6f>)")]
+    pub fn should_panic_if_path_contents_are_prefix_of_expected() {
+        let dir = TempDir::new("prefix-contents");
+        fs::write(dir.join("actual"), "Hello").unwrap();
+        fs::write(dir.join("expected"), "Hello World").unwrap();
+
+        assert_that(&dir.join("actual")).has_same_contents_as(dir.join("expected"));
+    }
+
+    #[test]
+    #[should_panic(expected = "/does-not-exist\"> could not be read")]
+    pub fn should_panic_if_path_to_compare_contents_with_cannot_be_read() {
+        let path = MANIFEST_PATH.to_string() + "/Cargo.toml";
+        let failing_path = MANIFEST_PATH.to_string() + "/does-not-exist";
+        assert_that(&Path::new(&path)).has_same_contents_as(Path::new(&failing_path));
+    }
+
+    #[test]
+    pub fn should_not_panic_if_path_is_an_empty_file() {
+        let dir = TempDir::new("empty-file");
+        fs::write(dir.join("empty"), "").unwrap();
+
+        assert_that(&dir.join("empty")).is_empty_file();
+        assert_that(&dir.join("empty").as_path()).is_empty_file();
+    }
+
+    #[test]
+    #[should_panic(expected = "-non-empty-file/file\"> to be an empty file\
+                   \n\t but was: a file of <5> bytes")]
+    pub fn should_panic_if_path_is_not_an_empty_file() {
+        let dir = TempDir::new("non-empty-file");
+        fs::write(dir.join("file"), "Hello").unwrap();
+
+        assert_that(&dir.join("file")).is_empty_file();
+    }
+
+    #[test]
+    pub fn should_not_panic_if_path_has_expected_file_size() {
+        let dir = TempDir::new("file-size");
+        fs::write(dir.join("file"), "Hello").unwrap();
+
+        assert_that(&dir.join("file")).has_file_size(5);
+        assert_that(&dir.join("file").as_path()).has_file_size(5);
+        assert_that(&dir.join("file")).has_file_size_at_least(5);
+        assert_that(&dir.join("file").as_path()).has_file_size_at_least(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "-unexpected-file-size/file\"> to have file size of <4> bytes\
+                   \n\t but was: <5> bytes")]
+    pub fn should_panic_if_path_does_not_have_expected_file_size() {
+        let dir = TempDir::new("unexpected-file-size");
+        fs::write(dir.join("file"), "Hello").unwrap();
+
+        assert_that(&dir.join("file")).has_file_size(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "-small-file-size/file\"> to have file size of at least <6> bytes\
+                   \n\t but was: <5> bytes")]
+    pub fn should_panic_if_path_has_file_size_less_than_expected() {
+        let dir = TempDir::new("small-file-size");
+        fs::write(dir.join("file"), "Hello").unwrap();
+
+        assert_that(&dir.join("file")).has_file_size_at_least(6);
+    }
+
+    #[test]
+    #[should_panic(expected = "/does-not-exist\"> to be a file\n\t but was: a non-existent Path")]
+    pub fn should_panic_if_path_for_file_size_does_not_exist() {
+        let failing_path = MANIFEST_PATH.to_string() + "/does-not-exist";
+        assert_that(&PathBuf::from(&failing_path)).has_file_size(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\"> to be a file\n\t but was: not a resolvable file")]
+    pub fn should_panic_if_path_for_file_size_is_not_a_file() {
+        assert_that(&Path::new(MANIFEST_PATH)).is_empty_file();
+    }
 }
@@ -1,14 +1,31 @@
-pub use super::{asserting, assert_that};
+pub use super::{asserting, assert_that, assert_that_mut};
 pub use super::boolean::BooleanAssertions;
-pub use super::hashmap::HashMapAssertions;
+pub use super::btreemap::BTreeMapAssertions;
+pub use super::checked::assert_that_result;
+pub use super::hashmap::{HashMapAssertions, HashMapCountAssertions, HashMapOptionAssertions};
+pub use super::hashset::HashSetAssertions;
 pub use super::iter::{ContainingIntoIterAssertions, ContainingIteratorAssertions,
                       MappingIterAssertions};
-pub use super::numeric::OrderedAssertions;
+pub use super::numeric::{AngleAssertions, CloseToAssertions, DurationAssertions,
+                         InstantAssertions, OrderedAssertions, PercentageAssertions,
+                         TimestampAssertions};
 pub use super::option::{OptionAssertions, ContainingOptionAssertions};
 pub use super::path::PathAssertions;
 pub use super::result::{ContainingResultAssertions, ResultAssertions};
 pub use super::string::StrAssertions;
-pub use super::vec::VecAssertions;
+pub use super::vec::{ByteAssertions, FloatVecAssertions, IntVecAssertions, NestedVecAssertions,
+                      StringVecAssertions, VecAssertions, VecOptionAssertions, VecResultAssertions,
+                      VecTupleAssertions};
 
-#[cfg(feature = "num")]
-pub use super::numeric::FloatAssertions;
+#[cfg(feature = "approx")]
+pub use super::approx::ApproxAssertions;
+#[cfg(feature = "serde")]
+pub use super::string::JsonAssertions;
+#[cfg(feature = "toml")]
+pub use super::string::TomlAssertions;
+#[cfg(feature = "yaml")]
+pub use super::string::YamlAssertions;
+#[cfg(feature = "time")]
+pub use super::time::OffsetDateTimeAssertions;
+#[cfg(feature = "url")]
+pub use super::url::{UrlAssertions, UrlStrAssertions};
@@ -9,6 +9,8 @@ pub trait ResultAssertions<'s, T, E>
 {
     fn is_ok(&mut self) -> Spec<'s, T>;
     fn is_err(&mut self) -> Spec<'s, E>;
+    fn is_ok_with_value_matching<F>(&mut self, predicate: F, description: &str)
+        where F: Fn(&T) -> bool;
 }
 
 pub trait ContainingResultAssertions<T, E>
@@ -145,6 +147,29 @@ impl<'s, T, E> ResultAssertions<'s, T, E> for Spec<'s, Result<T, E>>
             }
         }
     }
+
+    /// Asserts that the subject is an `Ok` containing a value which matches the provided
+    /// predicate. The description is included in the failure message to explain what the value
+    /// was expected to satisfy.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&Result::Ok::<usize, usize>(4))
+    ///     .is_ok_with_value_matching(|val| val % 2 == 0, "an even number");
+    /// ```
+    fn is_ok_with_value_matching<F>(&mut self, predicate: F, description: &str)
+        where F: Fn(&T) -> bool
+    {
+        let actual = match *self.subject {
+            Ok(ref val) if predicate(val) => return,
+            Ok(ref val) => format!("result[ok]<{:?}>", val),
+            Err(ref err) => format!("result[error]<{:?}>", err),
+        };
+
+        AssertionFailure::from_spec(self)
+            .with_expected(format!("result[ok] with value matching <{}>", description))
+            .with_actual(actual)
+            .fail();
+    }
 }
 
 #[cfg(test)]
@@ -266,4 +291,26 @@ mod tests {
         assert_that(&result).is_err_containing(&"Oh no");
     }
 
+    #[test]
+    fn should_not_panic_if_result_is_ok_with_value_matching_predicate() {
+        let result: Result<usize, &str> = Ok(4);
+        assert_that(&result).is_ok_with_value_matching(|val| val % 2 == 0, "an even number");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: result[ok] with value matching <an even number>\
+                   \n\t but was: result[ok]<3>")]
+    fn should_panic_if_result_is_ok_with_value_not_matching_predicate() {
+        let result: Result<usize, &str> = Ok(3);
+        assert_that(&result).is_ok_with_value_matching(|val| val % 2 == 0, "an even number");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: result[ok] with value matching <an even number>\
+                   \n\t but was: result[error]<\"Oh no\">")]
+    fn should_panic_if_result_is_err_but_expected_ok_with_value_matching_predicate() {
+        let result: Result<usize, &str> = Err("Oh no");
+        assert_that(&result).is_ok_with_value_matching(|val| val % 2 == 0, "an even number");
+    }
+
 }
@@ -1,12 +1,51 @@
 use super::{AssertionFailure, DescriptiveSpec, Spec};
 
+use std::any;
 use std::borrow::Borrow;
+use std::env;
+use std::fmt::{Debug, Display};
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde_json::{self, Value};
+
+#[cfg(feature = "toml")]
+use toml;
+
+#[cfg(feature = "yaml")]
+use serde_yaml;
+
+#[cfg(feature = "url")]
+use super::url;
 
 pub trait StrAssertions {
     fn starts_with<'r, E: Borrow<&'r str>>(&mut self, expected: E);
     fn ends_with<'r, E: Borrow<&'r str>>(&mut self, expected: E);
     fn contains<'r, E: Borrow<&'r str>>(&mut self, expected: E);
+    fn contains_all_in_order(&mut self, needles: &[&str]);
     fn is_empty(&mut self);
+    fn has_length_matching<F: Fn(usize) -> bool>(&mut self, predicate: F);
+    fn contains_only_whitespace(&mut self);
+    fn has_no_trailing_whitespace(&mut self);
+    fn has_no_leading_whitespace(&mut self);
+    fn is_valid_email_format(&mut self);
+    #[cfg(feature = "url")]
+    fn is_valid_url_format(&mut self);
+    fn matches_snapshot(&mut self, name: &str);
+    fn char_at_is(&mut self, byte_index: usize, expected: char);
+    fn nth_char_is(&mut self, n: usize, expected: char);
+    fn has_balanced_delimiters(&mut self, pairs: &[(char, char)]);
+    fn split_by_and_assert<'r, E, F>(&mut self, delimiter: E, assertions: F)
+        where E: Borrow<&'r str>,
+              F: FnOnce(Spec<Vec<&str>>);
+    fn split_once_and_assert<F>(&mut self, delimiter: char, assertions: F)
+        where F: for<'a> FnOnce(Spec<'a, &'a str>, Spec<'a, &'a str>);
+    fn parse_as_and_assert<T, F>(&mut self, assertions: F)
+        where T: FromStr + Debug,
+              T::Err: Display,
+              F: FnOnce(Spec<T>);
 }
 
 impl<'s> StrAssertions for Spec<'s, &'s str> {
@@ -40,6 +79,17 @@ impl<'s> StrAssertions for Spec<'s, &'s str> {
         contains(self, subject, expected);
     }
 
+    /// Asserts that the subject `&str` contains all of the provided `&str`s in the given order.
+    /// Gaps are allowed between them, but they must not overlap.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello World").contains_all_in_order(&["He", "o", "d"]);
+    /// ```
+    fn contains_all_in_order(&mut self, needles: &[&str]) {
+        let subject = self.subject;
+        contains_all_in_order(self, subject, needles);
+    }
+
     /// Asserts that the subject `&str` is empty.
     ///
     /// ```rust,ignore
@@ -49,6 +99,187 @@ impl<'s> StrAssertions for Spec<'s, &'s str> {
         let subject = self.subject;
         is_empty(self, subject);
     }
+
+    /// Asserts that the length of the subject `&str` in bytes satisfies the provided predicate.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"four").has_length_matching(|length| length % 2 == 0);
+    /// ```
+    fn has_length_matching<F: Fn(usize) -> bool>(&mut self, predicate: F) {
+        let subject = self.subject;
+        has_length_matching(self, subject, predicate);
+    }
+
+    /// Asserts that the subject `&str` is not empty and contains only whitespace.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&" \t\n").contains_only_whitespace();
+    /// ```
+    fn contains_only_whitespace(&mut self) {
+        let subject = self.subject;
+        contains_only_whitespace(self, subject);
+    }
+
+    /// Asserts that the subject `&str` does not end with whitespace.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"value").has_no_trailing_whitespace();
+    /// ```
+    fn has_no_trailing_whitespace(&mut self) {
+        let subject = self.subject;
+        has_no_trailing_whitespace(self, subject);
+    }
+
+    /// Asserts that the subject `&str` does not start with whitespace.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"value").has_no_leading_whitespace();
+    /// ```
+    fn has_no_leading_whitespace(&mut self) {
+        let subject = self.subject;
+        has_no_leading_whitespace(self, subject);
+    }
+
+    /// Asserts that the subject `&str` is structurally a valid email address. This is a basic
+    /// check for a single `@` between a local part and a dotted domain, not a full RFC 5322
+    /// parser.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"user@example.com").is_valid_email_format();
+    /// ```
+    fn is_valid_email_format(&mut self) {
+        let subject = self.subject;
+        is_valid_email_format(self, subject);
+    }
+
+    /// Asserts that the subject `&str` can be parsed as a URL. On failure the parse error is
+    /// included to show which part of the URL is malformed.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"https://example.com").is_valid_url_format();
+    /// ```
+    #[cfg(feature = "url")]
+    fn is_valid_url_format(&mut self) {
+        let subject = self.subject;
+        url::parse_url(self, subject);
+    }
+
+    /// Asserts that the subject `&str` matches the snapshot stored in
+    /// `tests/snapshots/<name>.snap` under the crate being tested.
+    ///
+    /// Set the `UPDATE_SNAPSHOTS` environment variable to create missing snapshots or to replace
+    /// snapshots which no longer match, then review the changes to the snapshot files.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello").matches_snapshot("greeting");
+    /// ```
+    fn matches_snapshot(&mut self, name: &str) {
+        let subject = self.subject;
+        matches_snapshot(self, subject, name);
+    }
+
+    /// Asserts that the subject `&str` has the expected `char` starting at the provided byte
+    /// index.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello").char_at_is(1, 'e');
+    /// ```
+    fn char_at_is(&mut self, byte_index: usize, expected: char) {
+        let subject = self.subject;
+        char_at_is(self, subject, byte_index, expected);
+    }
+
+    /// Asserts that the Nth `char` (counting from zero) of the subject `&str` is the expected
+    /// `char`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"héllo").nth_char_is(1, 'é');
+    /// ```
+    fn nth_char_is(&mut self, n: usize, expected: char) {
+        let subject = self.subject;
+        nth_char_is(self, subject, n, expected);
+    }
+
+    /// Asserts that the provided opening and closing delimiters are balanced and properly nested
+    /// in the subject `&str`. The position of the first imbalance is reported in `char`s.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"f(a[0], b)").has_balanced_delimiters(&[('(', ')'), ('[', ']')]);
+    /// ```
+    fn has_balanced_delimiters(&mut self, pairs: &[(char, char)]) {
+        let subject = self.subject;
+        has_balanced_delimiters(self, subject, pairs);
+    }
+
+    /// Splits the subject `&str` by the provided delimiter, then passes a `Spec` over the
+    /// substrings to the provided assertions.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"a,b,c").split_by_and_assert(",", |mut parts| parts.has_length(3));
+    /// ```
+    fn split_by_and_assert<'r, E, F>(&mut self, delimiter: E, assertions: F)
+        where E: Borrow<&'r str>,
+              F: FnOnce(Spec<Vec<&str>>)
+    {
+        let parts: Vec<&str> = self.subject.split(delimiter.borrow()).collect();
+        assertions(Spec {
+            subject: &parts,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
+
+    /// Splits the subject `&str` at the first occurrence of the provided delimiter, then passes a
+    /// `Spec` over each of the two parts to the provided assertions.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"key=value").split_once_and_assert('=', |mut key, mut value| {
+    ///     key.is_equal_to("key");
+    ///     value.is_equal_to("value");
+    /// });
+    /// ```
+    fn split_once_and_assert<F>(&mut self, delimiter: char, assertions: F)
+        where F: for<'a> FnOnce(Spec<'a, &'a str>, Spec<'a, &'a str>)
+    {
+        let subject = self.subject;
+        let (first, second) = split_once(self, subject, delimiter);
+
+        assertions(Spec {
+                       subject: &first,
+                       subject_name: self.subject_name,
+                       location: self.location.clone(),
+                       description: self.description,
+                   },
+                   Spec {
+                       subject: &second,
+                       subject_name: self.subject_name,
+                       location: self.location.clone(),
+                       description: self.description,
+                   });
+    }
+
+    /// Parses the subject `&str` as the requested type, then passes a `Spec` over the parsed value
+    /// to the provided assertions. Fails with the parse error if the subject can't be parsed.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"42").parse_as_and_assert(|mut number: Spec<u32>| number.is_equal_to(42));
+    /// ```
+    fn parse_as_and_assert<T, F>(&mut self, assertions: F)
+        where T: FromStr + Debug,
+              T::Err: Display,
+              F: FnOnce(Spec<T>)
+    {
+        let subject = self.subject;
+        let parsed: T = parse_as(self, subject);
+
+        assertions(Spec {
+            subject: &parsed,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
 }
 
 impl<'s> StrAssertions for Spec<'s, String> {
@@ -57,86 +288,758 @@ impl<'s> StrAssertions for Spec<'s, String> {
     /// ```rust,ignore
     /// assert_that(&"Hello".to_owned()).starts_with(&"H");
     /// ```
-    fn starts_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) {
-        let subject = &self.subject;
-        starts_with(self, subject, expected);
+    fn starts_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) {
+        let subject = &self.subject;
+        starts_with(self, subject, expected);
+    }
+
+    /// Asserts that the subject `String` ends with the provided `&str`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello".to_owned()).ends_with(&"o");
+    /// ```
+    fn ends_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) {
+        let subject = &self.subject;
+        ends_with(self, subject, expected);
+    }
+
+    /// Asserts that the subject `String` contains the provided `&str`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello".to_owned()).contains(&"e");
+    /// ```
+    fn contains<'r, E: Borrow<&'r str>>(&mut self, expected: E) {
+        let subject = &self.subject;
+        contains(self, subject, expected);
+    }
+
+    /// Asserts that the subject `String` contains all of the provided `&str`s in the given order.
+    /// Gaps are allowed between them, but they must not overlap.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello World".to_owned()).contains_all_in_order(&["He", "o", "d"]);
+    /// ```
+    fn contains_all_in_order(&mut self, needles: &[&str]) {
+        let subject = &self.subject;
+        contains_all_in_order(self, subject, needles);
+    }
+
+    /// Asserts that the subject `String` is empty.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"".to_owned()).is_empty();
+    /// ```
+    fn is_empty(&mut self) {
+        let subject = &self.subject;
+        is_empty(self, subject);
+    }
+
+    /// Asserts that the length of the subject `String` in bytes satisfies the provided predicate.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"four".to_owned()).has_length_matching(|length| length % 2 == 0);
+    /// ```
+    fn has_length_matching<F: Fn(usize) -> bool>(&mut self, predicate: F) {
+        let subject = &self.subject;
+        has_length_matching(self, subject, predicate);
+    }
+
+    /// Asserts that the subject `String` is not empty and contains only whitespace.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&" \t\n".to_owned()).contains_only_whitespace();
+    /// ```
+    fn contains_only_whitespace(&mut self) {
+        let subject = &self.subject;
+        contains_only_whitespace(self, subject);
+    }
+
+    /// Asserts that the subject `String` does not end with whitespace.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"value".to_owned()).has_no_trailing_whitespace();
+    /// ```
+    fn has_no_trailing_whitespace(&mut self) {
+        let subject = &self.subject;
+        has_no_trailing_whitespace(self, subject);
+    }
+
+    /// Asserts that the subject `String` does not start with whitespace.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"value".to_owned()).has_no_leading_whitespace();
+    /// ```
+    fn has_no_leading_whitespace(&mut self) {
+        let subject = &self.subject;
+        has_no_leading_whitespace(self, subject);
+    }
+
+    /// Asserts that the subject `String` is structurally a valid email address. This is a basic
+    /// check for a single `@` between a local part and a dotted domain, not a full RFC 5322
+    /// parser.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"user@example.com".to_owned()).is_valid_email_format();
+    /// ```
+    fn is_valid_email_format(&mut self) {
+        let subject = &self.subject;
+        is_valid_email_format(self, subject);
+    }
+
+    /// Asserts that the subject `String` can be parsed as a URL. On failure the parse error is
+    /// included to show which part of the URL is malformed.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"https://example.com".to_owned()).is_valid_url_format();
+    /// ```
+    #[cfg(feature = "url")]
+    fn is_valid_url_format(&mut self) {
+        let subject = &self.subject;
+        url::parse_url(self, subject);
+    }
+
+    /// Asserts that the subject `String` matches the snapshot stored in
+    /// `tests/snapshots/<name>.snap` under the crate being tested.
+    ///
+    /// Set the `UPDATE_SNAPSHOTS` environment variable to create missing snapshots or to replace
+    /// snapshots which no longer match, then review the changes to the snapshot files.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello".to_owned()).matches_snapshot("greeting");
+    /// ```
+    fn matches_snapshot(&mut self, name: &str) {
+        let subject = &self.subject;
+        matches_snapshot(self, subject, name);
+    }
+
+    /// Asserts that the subject `String` has the expected `char` starting at the provided byte
+    /// index.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello".to_owned()).char_at_is(1, 'e');
+    /// ```
+    fn char_at_is(&mut self, byte_index: usize, expected: char) {
+        let subject = &self.subject;
+        char_at_is(self, subject, byte_index, expected);
+    }
+
+    /// Asserts that the Nth `char` (counting from zero) of the subject `String` is the expected
+    /// `char`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"héllo".to_owned()).nth_char_is(1, 'é');
+    /// ```
+    fn nth_char_is(&mut self, n: usize, expected: char) {
+        let subject = &self.subject;
+        nth_char_is(self, subject, n, expected);
+    }
+
+    /// Asserts that the provided opening and closing delimiters are balanced and properly nested
+    /// in the subject `String`. The position of the first imbalance is reported in `char`s.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"f(a[0], b)".to_owned()).has_balanced_delimiters(&[('(', ')'), ('[', ']')]);
+    /// ```
+    fn has_balanced_delimiters(&mut self, pairs: &[(char, char)]) {
+        let subject = &self.subject;
+        has_balanced_delimiters(self, subject, pairs);
+    }
+
+    /// Splits the subject `String` by the provided delimiter, then passes a `Spec` over the
+    /// substrings to the provided assertions.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"a,b,c".to_owned()).split_by_and_assert(",", |mut parts| parts.has_length(3));
+    /// ```
+    fn split_by_and_assert<'r, E, F>(&mut self, delimiter: E, assertions: F)
+        where E: Borrow<&'r str>,
+              F: FnOnce(Spec<Vec<&str>>)
+    {
+        let parts: Vec<&str> = self.subject.split(delimiter.borrow()).collect();
+        assertions(Spec {
+            subject: &parts,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
+
+    /// Splits the subject `String` at the first occurrence of the provided delimiter, then passes a
+    /// `Spec` over each of the two parts to the provided assertions.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"key=value".to_owned()).split_once_and_assert('=', |mut key, mut value| {
+    ///     key.is_equal_to("key");
+    ///     value.is_equal_to("value");
+    /// });
+    /// ```
+    fn split_once_and_assert<F>(&mut self, delimiter: char, assertions: F)
+        where F: for<'a> FnOnce(Spec<'a, &'a str>, Spec<'a, &'a str>)
+    {
+        let subject = &self.subject;
+        let (first, second) = split_once(self, subject, delimiter);
+
+        assertions(Spec {
+                       subject: &first,
+                       subject_name: self.subject_name,
+                       location: self.location.clone(),
+                       description: self.description,
+                   },
+                   Spec {
+                       subject: &second,
+                       subject_name: self.subject_name,
+                       location: self.location.clone(),
+                       description: self.description,
+                   });
+    }
+
+    /// Parses the subject `String` as the requested type, then passes a `Spec` over the parsed
+    /// value to the provided assertions. Fails with the parse error if the subject can't be parsed.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"42".to_owned())
+    ///     .parse_as_and_assert(|mut number: Spec<u32>| number.is_equal_to(42));
+    /// ```
+    fn parse_as_and_assert<T, F>(&mut self, assertions: F)
+        where T: FromStr + Debug,
+              T::Err: Display,
+              F: FnOnce(Spec<T>)
+    {
+        let subject = &self.subject;
+        let parsed: T = parse_as(self, subject);
+
+        assertions(Spec {
+            subject: &parsed,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
+}
+
+impl<'s> Spec<'s, &'s str> {
+    /// Copies the subject `&str` into an owned `String`, then passes a `Spec` over it to the
+    /// provided assertions. As the `String` only lives for the duration of the call, it can't be
+    /// returned as a new `Spec`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"Hello").to_owned_string_and_assert(|mut owned| owned.ends_with("lo"));
+    /// ```
+    pub fn to_owned_string_and_assert<F: FnOnce(Spec<String>)>(&mut self, assertions: F) {
+        let owned = self.subject.to_string();
+
+        assertions(Spec {
+            subject: &owned,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
+}
+
+fn starts_with<'r, 's, S: DescriptiveSpec<'s>, E: Borrow<&'r str>>(spec: &'s S,
+                                                                   subject: &str,
+                                                                   expected: E) {
+    let borrowed_expected = expected.borrow();
+
+    if !subject.starts_with(borrowed_expected) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("string starting with <{:?}>", borrowed_expected))
+            .with_actual(format!("<{:?}>", subject))
+            .fail();
+    }
+}
+
+fn ends_with<'r, 's, S: DescriptiveSpec<'s>, E: Borrow<&'r str>>(spec: &'s S,
+                                                                 subject: &str,
+                                                                 expected: E) {
+    let borrowed_expected = expected.borrow();
+
+    if !subject.ends_with(borrowed_expected) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("string ending with <{:?}>", borrowed_expected))
+            .with_actual(format!("<{:?}>", subject))
+            .fail();
+    }
+}
+
+fn contains<'r, 's, S: DescriptiveSpec<'s>, E: Borrow<&'r str>>(spec: &'s S,
+                                                                subject: &str,
+                                                                expected: E) {
+    let borrowed_expected = expected.borrow();
+
+    if !subject.contains(borrowed_expected) {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("string containing <{:?}>", borrowed_expected))
+            .with_actual(format!("<{:?}>", subject))
+            .fail();
+    }
+}
+
+fn contains_all_in_order<'s, S: DescriptiveSpec<'s>>(spec: &'s S,
+                                                     subject: &str,
+                                                     needles: &[&str]) {
+    let mut position = 0;
+    let mut previous_needle = None;
+
+    for needle in needles {
+        if let Some(index) = subject[position..].find(needle) {
+            position += index + needle.len();
+            previous_needle = Some(needle);
+            continue;
+        }
+
+        let reason = match previous_needle {
+            Some(previous) if subject.contains(needle) => {
+                format!("<{:?}> not found after <{:?}>", needle, previous)
+            }
+            _ => format!("<{:?}> not found", needle),
+        };
+
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("string containing <{:?}> in order", needles))
+            .with_actual(format!("<{:?}> ({})", subject, reason))
+            .fail();
+    }
+}
+
+fn is_empty<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str) {
+    if !subject.is_empty() {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("an empty string"))
+            .with_actual(format!("<{:?}>", subject))
+            .fail();
+    }
+}
+
+fn has_length_matching<'s, S, F>(spec: &'s S, subject: &str, predicate: F)
+    where S: DescriptiveSpec<'s>,
+          F: Fn(usize) -> bool
+{
+    if !predicate(subject.len()) {
+        AssertionFailure::from_spec(spec)
+            .with_expected("string to have length matching predicate".to_string())
+            .with_actual(format!("<{}> (<{:?}>)", subject.len(), subject))
+            .fail();
+    }
+}
+
+fn contains_only_whitespace<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str) {
+    let actual_message = if subject.is_empty() {
+        "an empty string".to_string()
+    } else {
+        match subject.chars().enumerate().find(|&(_, character)| !character.is_whitespace()) {
+            Some((position, character)) => {
+                format!("<{:?}> (char <{:?}> at position <{}> is not whitespace)",
+                        subject,
+                        character,
+                        position)
+            }
+            None => return,
+        }
+    };
+
+    AssertionFailure::from_spec(spec)
+        .with_expected("a non-empty string containing only whitespace".to_string())
+        .with_actual(actual_message)
+        .fail();
+}
+
+fn has_no_trailing_whitespace<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str) {
+    let trimmed = subject.trim_end();
+
+    if subject != trimmed {
+        AssertionFailure::from_spec(spec)
+            .with_expected("string without trailing whitespace".to_string())
+            .with_actual(format!("<{:?}> (trailing whitespace <{:?}>)",
+                                 subject,
+                                 &subject[trimmed.len()..]))
+            .fail();
+    }
+}
+
+fn has_no_leading_whitespace<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str) {
+    let trimmed = subject.trim_start();
+
+    if subject != trimmed {
+        AssertionFailure::from_spec(spec)
+            .with_expected("string without leading whitespace".to_string())
+            .with_actual(format!("<{:?}> (leading whitespace <{:?}>)",
+                                 subject,
+                                 &subject[..subject.len() - trimmed.len()]))
+            .fail();
+    }
+}
+
+fn is_valid_email_format<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str) {
+    if let Some(problem) = find_email_format_problem(subject) {
+        AssertionFailure::from_spec(spec)
+            .with_expected("a valid email address".to_string())
+            .with_actual(format!("<{:?}> ({})", subject, problem))
+            .fail();
+    }
+}
+
+fn find_email_format_problem(subject: &str) -> Option<&'static str> {
+    if subject.chars().any(|character| character.is_whitespace() || character.is_control()) {
+        return Some("contains whitespace or control characters");
+    }
+
+    let (local_part, domain) = match subject.split_once('@') {
+        Some((_, domain)) if domain.contains('@') => return Some("contains more than one '@'"),
+        Some(parts) => parts,
+        None => return Some("missing '@'"),
+    };
+
+    if local_part.is_empty() {
+        Some("empty local part")
+    } else if local_part.starts_with('.') || local_part.ends_with('.') ||
+              local_part.contains("..") {
+        Some("misplaced '.' in local part")
+    } else if domain.is_empty() {
+        Some("empty domain")
+    } else if !domain.contains('.') {
+        Some("domain without a '.'")
+    } else if domain.split('.').any(|label| {
+        label.is_empty() || label.starts_with('-') || label.ends_with('-') ||
+        !label.chars().all(|character| character.is_alphanumeric() || character == '-')
+    }) {
+        Some("invalid domain label")
+    } else {
+        None
+    }
+}
+
+fn matches_snapshot<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str, name: &str) {
+    let should_update = env::var_os("UPDATE_SNAPSHOTS").is_some();
+    check_snapshot(spec, subject, name, should_update);
+}
+
+fn check_snapshot<'s, S: DescriptiveSpec<'s>>(spec: &'s S,
+                                              subject: &str,
+                                              name: &str,
+                                              should_update: bool) {
+    let manifest_dir = env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from).unwrap_or_default();
+    let snapshot_dir = manifest_dir.join("tests").join("snapshots");
+    let snapshot_path = snapshot_dir.join(format!("{}.snap", name));
+
+    let actual = match fs::read_to_string(&snapshot_path) {
+        Ok(ref snapshot) if snapshot == subject => return,
+        Ok(ref snapshot) if !should_update => {
+            format!("a string differing from it:{}", build_line_diff(snapshot, subject))
+        }
+        Err(_) if !should_update => {
+            format!("no snapshot at <{:?}> (set UPDATE_SNAPSHOTS to create it)",
+                    snapshot_path)
+        }
+        _ => {
+            let written = fs::create_dir_all(&snapshot_dir)
+                .and_then(|_| fs::write(&snapshot_path, subject));
+
+            if let Err(err) = written {
+                AssertionFailure::from_spec(spec)
+                    .fail_with_message(format!("snapshot <{:?}> could not be written: {}",
+                                               snapshot_path,
+                                               err));
+            }
+
+            return;
+        }
+    };
+
+    AssertionFailure::from_spec(spec)
+        .with_expected(format!("string matching snapshot <{}>", name))
+        .with_actual(actual)
+        .fail();
+}
+
+fn char_at_is<'s, S: DescriptiveSpec<'s>>(spec: &'s S,
+                                         subject: &str,
+                                         byte_index: usize,
+                                         expected: char) {
+    let expected_message = format!("string with char <{:?}> at byte index <{}>",
+                                   expected,
+                                   byte_index);
+
+    let actual_message = if byte_index >= subject.len() {
+        format!("<{:?}> (byte index out of bounds for length <{}>)",
+                subject,
+                subject.len())
+    } else if !subject.is_char_boundary(byte_index) {
+        format!("<{:?}> (byte index is not on a char boundary)", subject)
+    } else {
+        match subject[byte_index..].chars().next() {
+            Some(actual) if actual == expected => return,
+            Some(actual) => format!("char <{:?}>", actual),
+            None => unreachable!(),
+        }
+    };
+
+    AssertionFailure::from_spec(spec)
+        .with_expected(expected_message)
+        .with_actual(actual_message)
+        .fail();
+}
+
+fn nth_char_is<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str, n: usize, expected: char) {
+    let actual_message = match subject.chars().nth(n) {
+        Some(actual) if actual == expected => return,
+        Some(actual) => format!("char <{:?}>", actual),
+        None => format!("<{:?}> (only <{}> chars long)", subject, subject.chars().count()),
+    };
+
+    AssertionFailure::from_spec(spec)
+        .with_expected(format!("string with char <{:?}> at position <{}>", expected, n))
+        .with_actual(actual_message)
+        .fail();
+}
+
+fn has_balanced_delimiters<'s, S: DescriptiveSpec<'s>>(spec: &'s S,
+                                                      subject: &str,
+                                                      pairs: &[(char, char)]) {
+    // The delimiters which are still open, along with their position.
+    let mut open: Vec<(char, usize)> = vec![];
+    let mut problem = None;
+
+    for (position, character) in subject.chars().enumerate() {
+        if let Some(&(opener, _)) = pairs.iter().find(|&&(opener, _)| opener == character) {
+            open.push((opener, position));
+        } else if pairs.iter().any(|&(_, closer)| closer == character) {
+            match open.pop() {
+                Some((opener, _)) if pairs.contains(&(opener, character)) => {}
+                Some((opener, opener_position)) => {
+                    problem = Some(format!("closing <{:?}> at position <{}> which does not match \
+                                            opening <{:?}> at position <{}>",
+                                           character,
+                                           position,
+                                           opener,
+                                           opener_position));
+                    break;
+                }
+                None => {
+                    problem = Some(format!("unexpected closing <{:?}> at position <{}>",
+                                           character,
+                                           position));
+                    break;
+                }
+            }
+        }
+    }
+
+    if problem.is_none() {
+        problem = open.last().map(|&(opener, position)| {
+            format!("unclosed <{:?}> at position <{}>", opener, position)
+        });
+    }
+
+    if let Some(problem) = problem {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("string with balanced delimiters <{:?}>", pairs))
+            .with_actual(format!("<{:?}> with {}", subject, problem))
+            .fail();
+    }
+}
+
+fn split_once<'a, 's, S: DescriptiveSpec<'s>>(spec: &'s S,
+                                              subject: &'a str,
+                                              delimiter: char)
+                                              -> (&'a str, &'a str) {
+    match subject.split_once(delimiter) {
+        Some(parts) => parts,
+        None => {
+            AssertionFailure::from_spec(spec)
+                .with_expected(format!("string containing delimiter <{:?}>", delimiter))
+                .with_actual(format!("<{:?}>", subject))
+                .fail();
+
+            unreachable!();
+        }
+    }
+}
+
+fn parse_as<'s, S, T>(spec: &'s S, subject: &str) -> T
+    where S: DescriptiveSpec<'s>,
+          T: FromStr,
+          T::Err: Display
+{
+    match subject.parse::<T>() {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            AssertionFailure::from_spec(spec)
+                .with_expected(format!("string parsable as <{}>", any::type_name::<T>()))
+                .with_actual(format!("<{:?}> ({})", subject, error))
+                .fail();
+
+            unreachable!();
+        }
+    }
+}
+
+fn build_line_diff(expected: &str, actual: &str) -> String {
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+
+    // common_lengths[i][j] holds the length of the longest common subsequence of
+    // expected_lines[i..] and actual_lines[j..].
+    let mut common_lengths = vec![vec![0; actual_lines.len() + 1]; expected_lines.len() + 1];
+    for (i, expected_line) in expected_lines.iter().enumerate().rev() {
+        for (j, actual_line) in actual_lines.iter().enumerate().rev() {
+            common_lengths[i][j] = if expected_line == actual_line {
+                common_lengths[i + 1][j + 1] + 1
+            } else {
+                common_lengths[i + 1][j].max(common_lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < expected_lines.len() || j < actual_lines.len() {
+        if i < expected_lines.len() && j < actual_lines.len() &&
+           expected_lines[i] == actual_lines[j] {
+            diff.push_str(&format!("\n\t\t  {}", expected_lines[i]));
+            i += 1;
+            j += 1;
+        } else if i == expected_lines.len() ||
+                  (j < actual_lines.len() &&
+                   common_lengths[i][j + 1] > common_lengths[i + 1][j]) {
+            diff.push_str(&format!("\n\t\t+ {}", actual_lines[j]));
+            j += 1;
+        } else {
+            diff.push_str(&format!("\n\t\t- {}", expected_lines[i]));
+            i += 1;
+        }
+    }
+
+    diff
+}
+
+#[cfg(feature = "serde")]
+pub trait JsonAssertions {
+    fn is_valid_json(&mut self);
+}
+
+#[cfg(feature = "serde")]
+impl<'s> JsonAssertions for Spec<'s, &'s str> {
+    /// Asserts that the subject `&str` can be parsed as JSON. The failure message includes the
+    /// position of the parse error.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"{\"name\": \"spectral\"}").is_valid_json();
+    /// ```
+    fn is_valid_json(&mut self) {
+        let subject = self.subject;
+        is_valid_json(self, subject);
     }
+}
 
-    /// Asserts that the subject `String` ends with the provided `&str`.
+#[cfg(feature = "serde")]
+impl<'s> JsonAssertions for Spec<'s, String> {
+    /// Asserts that the subject `String` can be parsed as JSON. The failure message includes the
+    /// position of the parse error.
     ///
     /// ```rust,ignore
-    /// assert_that(&"Hello".to_owned()).ends_with(&"o");
+    /// assert_that(&"{\"name\": \"spectral\"}".to_owned()).is_valid_json();
     /// ```
-    fn ends_with<'r, E: Borrow<&'r str>>(&mut self, expected: E) {
+    fn is_valid_json(&mut self) {
         let subject = &self.subject;
-        ends_with(self, subject, expected);
+        is_valid_json(self, subject);
     }
+}
 
-    /// Asserts that the subject `String` contains the provided `&str`.
+#[cfg(feature = "serde")]
+fn is_valid_json<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str) {
+    if let Err(error) = serde_json::from_str::<Value>(subject) {
+        AssertionFailure::from_spec(spec)
+            .with_expected("valid JSON".to_string())
+            .with_actual(format!("invalid JSON ({})", error))
+            .fail();
+    }
+}
+
+#[cfg(feature = "toml")]
+pub trait TomlAssertions {
+    fn is_valid_toml(&mut self);
+}
+
+#[cfg(feature = "toml")]
+impl<'s> TomlAssertions for Spec<'s, &'s str> {
+    /// Asserts that the subject `&str` can be parsed as TOML. The failure message includes the
+    /// line and column of the parse error.
     ///
     /// ```rust,ignore
-    /// assert_that(&"Hello".to_owned()).contains(&"e");
+    /// assert_that(&"name = \"spectral\"").is_valid_toml();
     /// ```
-    fn contains<'r, E: Borrow<&'r str>>(&mut self, expected: E) {
-        let subject = &self.subject;
-        contains(self, subject, expected);
+    fn is_valid_toml(&mut self) {
+        let subject = self.subject;
+        is_valid_toml(self, subject);
     }
+}
 
-    /// Asserts that the subject `String` is empty.
+#[cfg(feature = "toml")]
+impl<'s> TomlAssertions for Spec<'s, String> {
+    /// Asserts that the subject `String` can be parsed as TOML. The failure message includes the
+    /// line and column of the parse error.
     ///
     /// ```rust,ignore
-    /// assert_that(&"".to_owned()).is_empty();
+    /// assert_that(&"name = \"spectral\"".to_owned()).is_valid_toml();
     /// ```
-    fn is_empty(&mut self) {
+    fn is_valid_toml(&mut self) {
         let subject = &self.subject;
-        is_empty(self, subject);
+        is_valid_toml(self, subject);
     }
 }
 
-fn starts_with<'r, 's, S: DescriptiveSpec<'s>, E: Borrow<&'r str>>(spec: &'s S,
-                                                                   subject: &str,
-                                                                   expected: E) {
-    let borrowed_expected = expected.borrow();
-
-    if !subject.starts_with(borrowed_expected) {
+#[cfg(feature = "toml")]
+fn is_valid_toml<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str) {
+    if let Err(error) = toml::from_str::<toml::Value>(subject) {
         AssertionFailure::from_spec(spec)
-            .with_expected(format!("string starting with <{:?}>", borrowed_expected))
-            .with_actual(format!("<{:?}>", subject))
+            .with_expected("valid TOML".to_string())
+            .with_actual(format!("invalid TOML ({})", error))
             .fail();
     }
 }
 
-fn ends_with<'r, 's, S: DescriptiveSpec<'s>, E: Borrow<&'r str>>(spec: &'s S,
-                                                                 subject: &str,
-                                                                 expected: E) {
-    let borrowed_expected = expected.borrow();
+#[cfg(feature = "yaml")]
+pub trait YamlAssertions {
+    fn is_valid_yaml(&mut self);
+}
 
-    if !subject.ends_with(borrowed_expected) {
-        AssertionFailure::from_spec(spec)
-            .with_expected(format!("string ending with <{:?}>", borrowed_expected))
-            .with_actual(format!("<{:?}>", subject))
-            .fail();
+#[cfg(feature = "yaml")]
+impl<'s> YamlAssertions for Spec<'s, &'s str> {
+    /// Asserts that the subject `&str` can be parsed as YAML. The failure message includes the
+    /// location of the parse error.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"name: spectral").is_valid_yaml();
+    /// ```
+    fn is_valid_yaml(&mut self) {
+        let subject = self.subject;
+        is_valid_yaml(self, subject);
     }
 }
 
-fn contains<'r, 's, S: DescriptiveSpec<'s>, E: Borrow<&'r str>>(spec: &'s S,
-                                                                subject: &str,
-                                                                expected: E) {
-    let borrowed_expected = expected.borrow();
-
-    if !subject.contains(borrowed_expected) {
-        AssertionFailure::from_spec(spec)
-            .with_expected(format!("string containing <{:?}>", borrowed_expected))
-            .with_actual(format!("<{:?}>", subject))
-            .fail();
+#[cfg(feature = "yaml")]
+impl<'s> YamlAssertions for Spec<'s, String> {
+    /// Asserts that the subject `String` can be parsed as YAML. The failure message includes the
+    /// location of the parse error.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"name: spectral".to_owned()).is_valid_yaml();
+    /// ```
+    fn is_valid_yaml(&mut self) {
+        let subject = &self.subject;
+        is_valid_yaml(self, subject);
     }
 }
 
-fn is_empty<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str) {
-    if !subject.is_empty() {
+#[cfg(feature = "yaml")]
+fn is_valid_yaml<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str) {
+    if let Err(error) = serde_yaml::from_str::<serde_yaml::Value>(subject) {
         AssertionFailure::from_spec(spec)
-            .with_expected(format!("an empty string"))
-            .with_actual(format!("<{:?}>", subject))
+            .with_expected("valid YAML".to_string())
+            .with_actual(format!("invalid YAML ({})", error))
             .fail();
     }
 }
@@ -144,6 +1047,8 @@ fn is_empty<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str) {
 #[cfg(test)]
 mod tests {
 
+    use super::check_snapshot;
+    use super::super::Spec;
     use super::super::prelude::*;
 
     #[test]
@@ -202,6 +1107,35 @@ mod tests {
         assert_that(&value).contains(&"A");
     }
 
+    #[test]
+    fn should_not_panic_if_str_contains_all_values_in_order() {
+        let value = "level=INFO user=bob action=login";
+        assert_that(&value).contains_all_in_order(&["INFO", "bob", "login"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string containing <[\"Hello\", \"World\"]> in order\
+                   \n\t but was: <\"World Hello\"> (<\"World\"> not found after <\"Hello\">)")]
+    fn should_panic_if_str_contains_all_values_out_of_order() {
+        let value = "World Hello";
+        assert_that(&value).contains_all_in_order(&["Hello", "World"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string containing <[\"Hello\", \"There\"]> in order\
+                   \n\t but was: <\"Hello World\"> (<\"There\"> not found)")]
+    fn should_panic_if_str_is_missing_value_expected_in_order() {
+        let value = "Hello World";
+        assert_that(&value).contains_all_in_order(&["Hello", "There"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "(<\"lo\"> not found after <\"Hell\">)")]
+    fn should_panic_if_str_values_expected_in_order_overlap() {
+        let value = "Hello";
+        assert_that(&value).contains_all_in_order(&["Hell", "lo"]);
+    }
+
     #[test]
     fn should_not_panic_if_str_is_empty() {
         let value = "";
@@ -271,6 +1205,20 @@ mod tests {
         assert_that(&value).contains(&"A");
     }
 
+    #[test]
+    fn should_not_panic_if_string_contains_all_values_in_order() {
+        let value = "level=INFO user=bob action=login".to_owned();
+        assert_that(&value).contains_all_in_order(&["INFO", "bob", "login"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string containing <[\"Hello\", \"World\"]> in order\
+                   \n\t but was: <\"World Hello\"> (<\"World\"> not found after <\"Hello\">)")]
+    fn should_panic_if_string_contains_all_values_out_of_order() {
+        let value = "World Hello".to_owned();
+        assert_that(&value).contains_all_in_order(&["Hello", "World"]);
+    }
+
     #[test]
     fn should_not_panic_if_string_is_empty() {
         let value = "".to_owned();
@@ -284,4 +1232,408 @@ mod tests {
         assert_that(&value).is_empty();
     }
 
+    #[test]
+    fn should_not_panic_if_str_matches_snapshot() {
+        let value = "Hello\nWorld\n";
+        assert_that(&value).matches_snapshot("greeting");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string matching snapshot <drifted-greeting>\
+                   \n\t but was: a string differing from it:\
+                   \n\t\t  Hello\n\t\t- World\n\t\t+ There\n\t\t  !")]
+    fn should_panic_if_str_does_not_match_snapshot() {
+        let value = "Hello\nThere\n!\n";
+        // Checked without updating, so that running with UPDATE_SNAPSHOTS doesn't overwrite it.
+        check_snapshot(&assert_that(&value), value, "drifted-greeting", false);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string matching snapshot <missing>\
+                   \n\t but was: no snapshot at <")]
+    fn should_panic_if_snapshot_does_not_exist() {
+        let value = "Hello";
+        check_snapshot(&assert_that(&value), value, "missing", false);
+    }
+
+    #[test]
+    fn should_not_panic_if_string_matches_snapshot() {
+        let value = "Hello\nWorld\n".to_owned();
+        assert_that(&value).matches_snapshot("greeting");
+    }
+
+    #[test]
+    fn should_not_panic_if_str_has_char_at_byte_index() {
+        assert_that(&"Hello").char_at_is(1, 'e');
+        assert_that(&"héllo").char_at_is(3, 'l');
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with char <'a'> at byte index <1>\
+                   \n\t but was: char <'e'>")]
+    fn should_panic_if_str_has_different_char_at_byte_index() {
+        assert_that(&"Hello").char_at_is(1, 'a');
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with char <'o'> at byte index <5>\
+                   \n\t but was: <\"Hello\"> (byte index out of bounds for length <5>)")]
+    fn should_panic_if_str_byte_index_is_out_of_bounds() {
+        assert_that(&"Hello").char_at_is(5, 'o');
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with char <'é'> at byte index <2>\
+                   \n\t but was: <\"héllo\"> (byte index is not on a char boundary)")]
+    fn should_panic_if_str_byte_index_is_not_on_char_boundary() {
+        assert_that(&"héllo").char_at_is(2, 'é');
+    }
+
+    #[test]
+    fn should_not_panic_if_string_has_char_at_byte_index() {
+        assert_that(&"Hello".to_owned()).char_at_is(4, 'o');
+    }
+
+    #[test]
+    fn should_not_panic_if_str_has_nth_char() {
+        assert_that(&"héllo").nth_char_is(1, 'é');
+        assert_that(&"héllo").nth_char_is(4, 'o');
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with char <'e'> at position <1>\
+                   \n\t but was: char <'é'>")]
+    fn should_panic_if_str_has_different_nth_char() {
+        assert_that(&"héllo").nth_char_is(1, 'e');
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with char <'!'> at position <5>\
+                   \n\t but was: <\"héllo\"> (only <5> chars long)")]
+    fn should_panic_if_str_is_too_short_for_nth_char() {
+        assert_that(&"héllo").nth_char_is(5, '!');
+    }
+
+    #[test]
+    fn should_not_panic_if_string_has_nth_char() {
+        assert_that(&"héllo".to_owned()).nth_char_is(2, 'l');
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn should_not_panic_if_string_is_valid_json() {
+        assert_that(&"{\"name\": \"spectral\", \"tags\": [1, 2]}".to_owned()).is_valid_json();
+        assert_that(&"[]").is_valid_json();
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    #[should_panic(expected = "\n\texpected: valid JSON\
+                   \n\t but was: invalid JSON (expected value at line 1 column 10)")]
+    fn should_panic_if_string_is_not_valid_json() {
+        assert_that(&"{\"name\": }".to_owned()).is_valid_json();
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    #[should_panic(expected = "\n\texpected: valid JSON\
+                   \n\t but was: invalid JSON (expected value at line 3 column 1)")]
+    fn should_panic_with_line_of_error_if_multiline_string_is_not_valid_json() {
+        assert_that(&"{\n  \"name\":\n}").is_valid_json();
+    }
+
+    #[test]
+    #[cfg(feature = "toml")]
+    fn should_not_panic_if_string_is_valid_toml() {
+        assert_that(&"[package]\nname = \"spectral\"\n".to_owned()).is_valid_toml();
+        assert_that(&"").is_valid_toml();
+    }
+
+    #[test]
+    #[cfg(feature = "toml")]
+    #[should_panic(expected = "\n\texpected: valid TOML\
+                   \n\t but was: invalid TOML (expected an equals, found a newline at line 3 \
+                   column 8)")]
+    fn should_panic_if_string_is_not_valid_toml() {
+        assert_that(&"[package]\nname = \"spectral\"\nversion\n".to_owned()).is_valid_toml();
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_str_split_by_delimiter() {
+        assert_that(&"a,b,c").split_by_and_assert(",", |mut parts| {
+            parts.has_length(3);
+            parts.is_equal_to(vec!["a", "b", "c"]);
+        });
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_str_split_by_multi_char_delimiter() {
+        assert_that(&"key => value => other").split_by_and_assert(" => ", |mut parts| {
+            parts.is_equal_to(vec!["key", "value", "other"]);
+        });
+    }
+
+    #[test]
+    fn should_keep_trailing_empty_substring_when_str_ends_with_delimiter() {
+        assert_that(&"a,b,".to_owned())
+            .split_by_and_assert(",", |mut parts| parts.is_equal_to(vec!["a", "b", ""]));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [csv]\
+                   \n\texpected: vec to have length <2>\n\t but was: <3>")]
+    fn should_panic_with_subject_name_if_str_split_by_delimiter_does_not_match() {
+        assert_that(&"a,b,c")
+            .named("csv")
+            .split_by_and_assert(",", |mut parts| parts.has_length(2));
+    }
+
+    #[test]
+    #[cfg(feature = "yaml")]
+    fn should_not_panic_if_string_is_valid_yaml() {
+        assert_that(&"name: spectral\ntags:\n  - fluent\n".to_owned()).is_valid_yaml();
+    }
+
+    #[test]
+    #[cfg(feature = "yaml")]
+    #[should_panic(expected = "\n\texpected: valid YAML\
+                   \n\t but was: invalid YAML (mapping values are not allowed in this context \
+                   at line 2 column")]
+    fn should_panic_if_string_is_not_valid_yaml() {
+        assert_that(&"name: spectral\nversion: 1: 2\n".to_owned()).is_valid_yaml();
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_parts_of_str_split_once() {
+        assert_that(&"key=value=other").split_once_and_assert('=', |mut key, mut value| {
+            key.is_equal_to("key");
+            value.is_equal_to("value=other");
+        });
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_parts_of_string_split_once() {
+        assert_that(&"key=".to_owned()).split_once_and_assert('=', |mut key, mut value| {
+            key.starts_with("k");
+            value.is_empty();
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string containing delimiter <'='>\
+                   \n\t but was: <\"key:value\">")]
+    fn should_panic_if_str_to_split_once_does_not_contain_delimiter() {
+        assert_that(&"key:value").split_once_and_assert('=', |_, _| {});
+    }
+
+    #[test]
+    fn should_not_panic_if_str_contains_only_whitespace() {
+        assert_that(&" \t\n").contains_only_whitespace();
+        assert_that(&"\u{a0}".to_owned()).contains_only_whitespace();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a non-empty string containing only whitespace\
+                   \n\t but was: an empty string")]
+    fn should_panic_if_str_expected_to_contain_only_whitespace_is_empty() {
+        assert_that(&"").contains_only_whitespace();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a non-empty string containing only whitespace\
+                   \n\t but was: <\"  a b\"> (char <'a'> at position <2> is not whitespace)")]
+    fn should_panic_if_str_contains_non_whitespace() {
+        assert_that(&"  a b".to_owned()).contains_only_whitespace();
+    }
+
+    #[test]
+    fn should_not_panic_if_str_has_no_trailing_whitespace() {
+        assert_that(&"  value").has_no_trailing_whitespace();
+        assert_that(&"".to_owned()).has_no_trailing_whitespace();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string without trailing whitespace\
+                   \n\t but was: <\"value \\n\\t\"> (trailing whitespace <\" \\n\\t\">)")]
+    fn should_panic_if_str_has_trailing_whitespace() {
+        assert_that(&"value \n\t").has_no_trailing_whitespace();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [line]\
+                   \n\texpected: string without trailing whitespace\
+                   \n\t but was: <\"value\\r\\n\"> (trailing whitespace <\"\\r\\n\">)")]
+    fn should_panic_if_string_has_trailing_whitespace() {
+        assert_that(&"value\r\n".to_owned()).named("line").has_no_trailing_whitespace();
+    }
+
+    #[test]
+    fn should_not_panic_if_str_has_no_leading_whitespace() {
+        assert_that(&"value  ").has_no_leading_whitespace();
+        assert_that(&"".to_owned()).has_no_leading_whitespace();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string without leading whitespace\
+                   \n\t but was: <\"\\t value\"> (leading whitespace <\"\\t \">)")]
+    fn should_panic_if_str_has_leading_whitespace() {
+        assert_that(&"\t value").has_no_leading_whitespace();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [line]\
+                   \n\texpected: string without leading whitespace\
+                   \n\t but was: <\"\\n\\nvalue\"> (leading whitespace <\"\\n\\n\">)")]
+    fn should_panic_if_string_has_leading_whitespace() {
+        assert_that(&"\n\nvalue".to_owned()).named("line").has_no_leading_whitespace();
+    }
+
+    #[test]
+    fn should_not_panic_if_str_is_valid_email_format() {
+        assert_that(&"user@example.com").is_valid_email_format();
+        assert_that(&"first.last+tag@mail.example-domain.org".to_owned()).is_valid_email_format();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a valid email address\
+                   \n\t but was: <\"user.example.com\"> (missing '@')")]
+    fn should_panic_if_email_is_missing_at_sign() {
+        assert_that(&"user.example.com").is_valid_email_format();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [email]\
+                   \n\texpected: a valid email address\
+                   \n\t but was: <\"user@@example.com\"> (contains more than one '@')")]
+    fn should_panic_if_email_has_more_than_one_at_sign() {
+        assert_that(&"user@@example.com".to_owned()).named("email").is_valid_email_format();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a valid email address\
+                   \n\t but was: <\"@example.com\"> (empty local part)")]
+    fn should_panic_if_email_has_empty_local_part() {
+        assert_that(&"@example.com").is_valid_email_format();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a valid email address\
+                   \n\t but was: <\"user@localhost\"> (domain without a '.')")]
+    fn should_panic_if_email_domain_has_no_dot() {
+        assert_that(&"user@localhost").is_valid_email_format();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a valid email address\
+                   \n\t but was: <\"user@example..com\"> (invalid domain label)")]
+    fn should_panic_if_email_domain_has_empty_label() {
+        assert_that(&"user@example..com").is_valid_email_format();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a valid email address\
+                   \n\t but was: <\"us er@example.com\"> (contains whitespace or control \
+                   characters)")]
+    fn should_panic_if_email_contains_whitespace() {
+        assert_that(&"us er@example.com").is_valid_email_format();
+    }
+
+    #[test]
+    #[cfg(feature = "url")]
+    fn should_not_panic_if_str_is_valid_url_format() {
+        assert_that(&"https://example.com/docs?page=2").is_valid_url_format();
+        assert_that(&"mailto:someone@example.com".to_owned()).is_valid_url_format();
+    }
+
+    #[test]
+    #[cfg(feature = "url")]
+    #[should_panic(expected = "\n\texpected: a valid URL\
+                   \n\t but was: <\"example.com/docs\"> (relative URL without a base)")]
+    fn should_panic_if_str_is_not_valid_url_format() {
+        assert_that(&"example.com/docs").is_valid_url_format();
+    }
+
+    #[test]
+    #[cfg(feature = "url")]
+    #[should_panic(expected = "\n\tfor subject [homepage]\n\texpected: a valid URL\
+                   \n\t but was: <\"https://\"> (empty host)")]
+    fn should_panic_if_string_has_invalid_url_host() {
+        assert_that(&"https://".to_owned()).named("homepage").is_valid_url_format();
+    }
+
+    #[test]
+    fn should_not_panic_if_str_length_matches_predicate() {
+        assert_that(&"four").has_length_matching(|length| length % 2 == 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string to have length matching predicate\
+                   \n\t but was: <5> (<\"three\">)")]
+    fn should_panic_if_string_length_does_not_match_predicate() {
+        assert_that(&"three".to_owned()).has_length_matching(|length| length % 2 == 0);
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_owned_string() {
+        assert_that(&"Hello").to_owned_string_and_assert(|mut owned| {
+            owned.is_equal_to("Hello".to_string());
+            owned.ends_with("lo");
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [greeting]\
+                   \n\texpected: string starting with <\"Bye\">\n\t but was: <\"Hello\">")]
+    fn should_panic_with_subject_name_if_owned_string_assertion_fails() {
+        assert_that(&"Hello")
+            .named("greeting")
+            .to_owned_string_and_assert(|mut owned| owned.starts_with("Bye"));
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_parsed_value() {
+        assert_that(&"42").parse_as_and_assert(|mut number: Spec<u32>| number.is_equal_to(42));
+        assert_that(&"1.5".to_owned())
+            .parse_as_and_assert(|mut number: Spec<f64>| number.is_greater_than(1.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [port]\n\texpected: string parsable as <u16>\
+                   \n\t but was: <\"http\"> (invalid digit found in string)")]
+    fn should_panic_if_str_cannot_be_parsed() {
+        assert_that(&"http").named("port").parse_as_and_assert(|_: Spec<u16>| {});
+    }
+
+    #[test]
+    fn should_not_panic_if_str_has_balanced_delimiters() {
+        assert_that(&"f(a[0], (b))").has_balanced_delimiters(&[('(', ')'), ('[', ']')]);
+        assert_that(&"no delimiters".to_owned()).has_balanced_delimiters(&[('(', ')')]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [expression]\
+                   \n\texpected: string with balanced delimiters <[('(', ')')]>\
+                   \n\t but was: <\"(a + (b)\"> with unclosed <'('> at position <0>")]
+    fn should_panic_if_str_has_unclosed_delimiter() {
+        assert_that(&"(a + (b)")
+            .named("expression")
+            .has_balanced_delimiters(&[('(', ')')]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with balanced delimiters \
+                   <[('(', ')'), ('[', ']')]>\
+                   \n\t but was: <\"f(a[0)]\"> with closing <')'> at position <5> which does \
+                   not match opening <'['> at position <3>")]
+    fn should_panic_if_str_has_mismatched_closing_delimiter() {
+        assert_that(&"f(a[0)]".to_owned()).has_balanced_delimiters(&[('(', ')'), ('[', ']')]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string with balanced delimiters <[('(', ')')]>\
+                   \n\t but was: <\"a)\"> with unexpected closing <')'> at position <1>")]
+    fn should_panic_if_str_has_unexpected_closing_delimiter() {
+        assert_that(&"a)").has_balanced_delimiters(&[('(', ')')]);
+    }
+
 }
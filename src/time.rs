@@ -0,0 +1,155 @@
+use super::{AssertionFailure, Spec};
+
+use time_crate::{Duration, OffsetDateTime};
+
+use std::borrow::Borrow;
+
+pub trait OffsetDateTimeAssertions {
+    fn is_before<E: Borrow<OffsetDateTime>>(&mut self, other: E);
+    fn is_after<E: Borrow<OffsetDateTime>>(&mut self, other: E);
+    fn is_within<E: Borrow<OffsetDateTime>>(&mut self, expected: E, tolerance: Duration);
+}
+
+impl<'s> OffsetDateTimeAssertions for Spec<'s, OffsetDateTime> {
+    /// Asserts that the subject `OffsetDateTime` is strictly before the other datetime.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&OffsetDateTime::UNIX_EPOCH)
+    ///     .is_before(OffsetDateTime::UNIX_EPOCH + Duration::seconds(1));
+    /// ```
+    fn is_before<E: Borrow<OffsetDateTime>>(&mut self, other: E) {
+        let subject = *self.subject;
+        let borrowed_other = *other.borrow();
+
+        if subject >= borrowed_other {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("datetime before <{}>", borrowed_other))
+                .with_actual(format!("<{}> (<{:?}> seconds after)",
+                                     subject,
+                                     (subject - borrowed_other).as_seconds_f64()))
+                .fail();
+        }
+    }
+
+    /// Asserts that the subject `OffsetDateTime` is strictly after the other datetime.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&(OffsetDateTime::UNIX_EPOCH + Duration::seconds(1)))
+    ///     .is_after(OffsetDateTime::UNIX_EPOCH);
+    /// ```
+    fn is_after<E: Borrow<OffsetDateTime>>(&mut self, other: E) {
+        let subject = *self.subject;
+        let borrowed_other = *other.borrow();
+
+        if subject <= borrowed_other {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("datetime after <{}>", borrowed_other))
+                .with_actual(format!("<{}> (<{:?}> seconds before)",
+                                     subject,
+                                     (borrowed_other - subject).as_seconds_f64()))
+                .fail();
+        }
+    }
+
+    /// Asserts that the subject `OffsetDateTime` is within the specified tolerance of the expected
+    /// datetime, in either direction.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&(OffsetDateTime::UNIX_EPOCH + Duration::milliseconds(500)))
+    ///     .is_within(OffsetDateTime::UNIX_EPOCH, Duration::seconds(1));
+    /// ```
+    fn is_within<E: Borrow<OffsetDateTime>>(&mut self, expected: E, tolerance: Duration) {
+        let subject = *self.subject;
+        let borrowed_expected = *expected.borrow();
+        let difference = (subject - borrowed_expected).abs();
+
+        if difference > tolerance.abs() {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("datetime within <{:?}> seconds of <{}>",
+                                       tolerance.abs().as_seconds_f64(),
+                                       borrowed_expected))
+                .with_actual(format!("<{}> (<{:?}> seconds apart)",
+                                     subject,
+                                     difference.as_seconds_f64()))
+                .fail();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+
+    use time_crate::{Duration, OffsetDateTime};
+
+    fn epoch_plus_seconds(seconds: i64) -> OffsetDateTime {
+        OffsetDateTime::UNIX_EPOCH + Duration::seconds(seconds)
+    }
+
+    #[test]
+    fn should_not_panic_if_datetime_is_before_other() {
+        assert_that(&epoch_plus_seconds(10)).is_before(epoch_plus_seconds(11));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [deadline]\n\texpected: datetime before <")]
+    fn should_panic_with_subject_name_if_datetime_is_not_before_other() {
+        assert_that(&epoch_plus_seconds(10)).named("deadline").is_before(epoch_plus_seconds(9));
+    }
+
+    #[test]
+    #[should_panic(expected = "> (<1.5> seconds after)")]
+    fn should_panic_with_difference_if_datetime_is_not_before_other() {
+        let subject = epoch_plus_seconds(10) + Duration::milliseconds(500);
+        assert_that(&subject).is_before(epoch_plus_seconds(9));
+    }
+
+    #[test]
+    #[should_panic(expected = "> (<0.0> seconds after)")]
+    fn should_panic_if_datetime_is_equal_to_but_expected_before() {
+        assert_that(&epoch_plus_seconds(10)).is_before(epoch_plus_seconds(10));
+    }
+
+    #[test]
+    fn should_not_panic_if_datetime_is_after_other() {
+        assert_that(&epoch_plus_seconds(10)).is_after(epoch_plus_seconds(9));
+    }
+
+    #[test]
+    #[should_panic(expected = "> (<2.0> seconds before)")]
+    fn should_panic_if_datetime_is_not_after_other() {
+        assert_that(&epoch_plus_seconds(10)).is_after(epoch_plus_seconds(12));
+    }
+
+    #[test]
+    fn should_not_panic_if_datetime_is_within_tolerance() {
+        let tolerance = Duration::seconds(1);
+
+        assert_that(&epoch_plus_seconds(10)).is_within(epoch_plus_seconds(11), tolerance);
+        assert_that(&epoch_plus_seconds(10)).is_within(epoch_plus_seconds(9), tolerance);
+    }
+
+    #[test]
+    #[should_panic(expected = "> (<3.0> seconds apart)")]
+    fn should_panic_if_datetime_is_not_within_tolerance() {
+        assert_that(&epoch_plus_seconds(10)).is_within(epoch_plus_seconds(7), Duration::seconds(2));
+    }
+
+    #[test]
+    fn should_be_able_to_use_close_to_and_ordering_with_time_durations() {
+        assert_that(&Duration::milliseconds(1_050))
+            .is_close_to(Duration::seconds(1), Duration::milliseconds(100));
+        assert_that(&Duration::seconds(-2))
+            .is_close_to(Duration::seconds(-1), Duration::seconds(1));
+        assert_that(&Duration::seconds(1)).is_less_than(Duration::seconds(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: value close to")]
+    fn should_panic_if_time_duration_is_not_close_to_expected() {
+        assert_that(&Duration::seconds(3))
+            .is_close_to(Duration::seconds(1), Duration::seconds(1));
+    }
+
+}
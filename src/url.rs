@@ -0,0 +1,203 @@
+use super::{AssertionFailure, DescriptiveSpec, Spec};
+
+use url_crate::Url;
+
+use std::borrow::Borrow;
+
+pub trait UrlStrAssertions {
+    fn is_valid_url(&mut self);
+    fn is_valid_url_and_assert<F: FnOnce(Spec<Url>)>(&mut self, assertions: F);
+}
+
+pub trait UrlAssertions {
+    fn has_scheme<'r, E: Borrow<&'r str>>(&mut self, expected_scheme: E);
+    fn has_host<'r, E: Borrow<&'r str>>(&mut self, expected_host: E);
+    fn has_path<'r, E: Borrow<&'r str>>(&mut self, expected_path: E);
+}
+
+impl<'s> UrlStrAssertions for Spec<'s, &'s str> {
+    /// Asserts that the subject `&str` can be parsed as a URL.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"https://example.com").is_valid_url();
+    /// ```
+    fn is_valid_url(&mut self) {
+        let subject = self.subject;
+        parse_url(self, subject);
+    }
+
+    /// Asserts that the subject `&str` can be parsed as a URL, then passes a `Spec` over the parsed
+    /// `Url` to the provided assertions.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"https://example.com")
+    ///     .is_valid_url_and_assert(|mut url| url.has_scheme("https"));
+    /// ```
+    fn is_valid_url_and_assert<F: FnOnce(Spec<Url>)>(&mut self, assertions: F) {
+        let subject = self.subject;
+        let url = parse_url(self, subject);
+
+        assertions(Spec {
+            subject: &url,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
+}
+
+impl<'s> UrlStrAssertions for Spec<'s, String> {
+    /// Asserts that the subject `String` can be parsed as a URL.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"https://example.com".to_owned()).is_valid_url();
+    /// ```
+    fn is_valid_url(&mut self) {
+        let subject = &self.subject;
+        parse_url(self, subject);
+    }
+
+    /// Asserts that the subject `String` can be parsed as a URL, then passes a `Spec` over the
+    /// parsed `Url` to the provided assertions.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&"https://example.com".to_owned())
+    ///     .is_valid_url_and_assert(|mut url| url.has_scheme("https"));
+    /// ```
+    fn is_valid_url_and_assert<F: FnOnce(Spec<Url>)>(&mut self, assertions: F) {
+        let subject = &self.subject;
+        let url = parse_url(self, subject);
+
+        assertions(Spec {
+            subject: &url,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
+}
+
+impl<'s> UrlAssertions for Spec<'s, Url> {
+    /// Asserts that the subject `Url` has the expected scheme.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&Url::parse("https://example.com").unwrap()).has_scheme("https");
+    /// ```
+    fn has_scheme<'r, E: Borrow<&'r str>>(&mut self, expected_scheme: E) {
+        let subject = self.subject;
+        let borrowed_expected_scheme = expected_scheme.borrow();
+
+        if subject.scheme() != *borrowed_expected_scheme {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("URL with scheme <{:?}>", borrowed_expected_scheme))
+                .with_actual(format!("<{:?}> of <{}>", subject.scheme(), subject))
+                .fail();
+        }
+    }
+
+    /// Asserts that the subject `Url` has the expected host.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&Url::parse("https://example.com").unwrap()).has_host("example.com");
+    /// ```
+    fn has_host<'r, E: Borrow<&'r str>>(&mut self, expected_host: E) {
+        let subject = self.subject;
+        let borrowed_expected_host = expected_host.borrow();
+
+        let actual_message = match subject.host_str() {
+            Some(host) if host == *borrowed_expected_host => return,
+            Some(host) => format!("<{:?}> of <{}>", host, subject),
+            None => format!("<{}> without a host", subject),
+        };
+
+        AssertionFailure::from_spec(self)
+            .with_expected(format!("URL with host <{:?}>", borrowed_expected_host))
+            .with_actual(actual_message)
+            .fail();
+    }
+
+    /// Asserts that the subject `Url` has the expected path.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&Url::parse("https://example.com/docs").unwrap()).has_path("/docs");
+    /// ```
+    fn has_path<'r, E: Borrow<&'r str>>(&mut self, expected_path: E) {
+        let subject = self.subject;
+        let borrowed_expected_path = expected_path.borrow();
+
+        if subject.path() != *borrowed_expected_path {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("URL with path <{:?}>", borrowed_expected_path))
+                .with_actual(format!("<{:?}> of <{}>", subject.path(), subject))
+                .fail();
+        }
+    }
+}
+
+pub(crate) fn parse_url<'s, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &str) -> Url {
+    match Url::parse(subject) {
+        Ok(url) => url,
+        Err(error) => {
+            AssertionFailure::from_spec(spec)
+                .with_expected("a valid URL".to_string())
+                .with_actual(format!("<{:?}> ({})", subject, error))
+                .fail();
+
+            unreachable!();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+
+    #[test]
+    fn should_be_able_to_assert_on_valid_url() {
+        assert_that(&"https://example.com/docs").is_valid_url_and_assert(|mut url| {
+            url.has_scheme("https");
+            url.has_host("example.com");
+            url.has_path("/docs");
+        });
+    }
+
+    #[test]
+    fn should_not_panic_if_string_is_valid_url() {
+        assert_that(&"https://example.com".to_owned()).is_valid_url();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a valid URL\
+                   \n\t but was: <\"example.com/docs\"> (relative URL without a base)")]
+    fn should_panic_if_str_is_not_valid_url() {
+        assert_that(&"example.com/docs").is_valid_url();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [endpoint]\n\texpected: URL with scheme <\"https\">\
+                   \n\t but was: <\"http\"> of <http://example.com/>")]
+    fn should_panic_if_url_does_not_have_scheme() {
+        assert_that(&"http://example.com".to_owned())
+            .named("endpoint")
+            .is_valid_url_and_assert(|mut url| url.has_scheme("https"));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: URL with host <\"example.com\">\
+                   \n\t but was: <mailto:someone> without a host")]
+    fn should_panic_if_url_does_not_have_host() {
+        assert_that(&"mailto:someone").is_valid_url_and_assert(|mut url| {
+            url.has_host("example.com");
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: URL with path <\"/docs\">\
+                   \n\t but was: <\"/\"> of <https://example.com/>")]
+    fn should_panic_if_url_does_not_have_path() {
+        assert_that(&"https://example.com")
+            .is_valid_url_and_assert(|mut url| url.has_path("/docs"));
+    }
+
+}
@@ -1,11 +1,137 @@
-use super::{AssertionFailure, Spec};
+use super::{assert_that, checked, AssertionFailure, DescriptiveSpec, Spec};
 
-pub trait VecAssertions {
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::iter::{Product, Sum};
+use std::ops::Sub;
+use std::str;
+
+pub trait VecAssertions<'s, T: 's> {
     fn has_length(&mut self, expected: usize);
+    fn has_length_matching<F: Fn(usize) -> bool>(&mut self, predicate: F);
     fn is_empty(&mut self);
+    fn group_by_and_assert<K, F, G>(&mut self, key_fn: F, assertions: G)
+        where T: Clone,
+              K: Hash + Eq + Debug,
+              F: Fn(&T) -> K,
+              G: Fn(Spec<Vec<T>>);
+    fn max_element(&mut self) -> Spec<'s, T> where T: Ord + Debug;
+    fn min_element(&mut self) -> Spec<'s, T> where T: Ord + Debug;
+    fn sum_is<E: Borrow<T>>(&mut self, expected: E) where T: Sum<&'s T> + PartialEq + Debug;
+    fn product_is<E: Borrow<T>>(&mut self, expected: E)
+        where T: Product<&'s T> + PartialEq + Debug;
+    fn increases_by_at_least<E: Borrow<T>>(&mut self, step: E)
+        where T: Sub<Output = T> + PartialOrd + Copy + Debug;
+    fn contains_run_of<E: Borrow<T>>(&mut self, value: E, length: usize)
+        where T: PartialEq + Debug;
+    fn has_element_at_satisfying<F>(&mut self, index: usize, predicate: F, description: &str)
+        where T: Debug,
+              F: Fn(&T) -> bool;
+    fn first_n_and_assert<F>(&mut self, n: usize, assertions: F)
+        where T: Clone,
+              F: FnOnce(Spec<Vec<T>>);
+    fn last_n_and_assert<F>(&mut self, n: usize, assertions: F)
+        where T: Clone,
+              F: FnOnce(Spec<Vec<T>>);
+    fn each_matching_any_of<F>(&mut self, predicates: Vec<F>)
+        where T: Debug,
+              F: Fn(&T) -> bool;
+    fn adjacent_pairs_satisfy<F>(&mut self, predicate: F, description: &str)
+        where T: Debug,
+              F: Fn(&T, &T) -> bool;
+    fn has_exactly_one_satisfying<F>(&mut self, predicate: F) -> Spec<'s, T>
+        where T: Debug,
+              F: Fn(&T) -> bool;
+    fn is_topologically_sorted_given<F>(&mut self, deps: F)
+        where T: PartialEq + Debug,
+              F: Fn(&T) -> Vec<T>;
+    fn is_rotation_of<E: Borrow<T>>(&mut self, expected: &[E]) where T: PartialEq + Debug;
+    fn is_prefix_of<E: Borrow<T>>(&mut self, full: &[E]) where T: PartialEq + Debug;
+    fn is_suffix_of<E: Borrow<T>>(&mut self, full: &[E]) where T: PartialEq + Debug;
+    fn is_subsequence_of<E: Borrow<T>>(&mut self, full: &[E]) where T: PartialEq + Debug;
+    fn is_interleaved_with<U: Debug, F: Fn(Spec<T>, Spec<U>)>(&mut self, other: &'s [U], f: F);
+    fn map_elements_and_assert<U, F, G>(&mut self, f: F, assertions: G)
+        where U: Debug,
+              F: Fn(&T) -> U,
+              G: FnOnce(Spec<Vec<U>>);
+    fn contains_subsequence_matching<F>(&mut self, predicates: Vec<F>)
+        where T: Debug,
+              F: Fn(&T) -> bool;
+    fn count_distinct_is(&mut self, expected: usize) where T: Hash + Eq + Debug;
+    fn is_sorted_stably_by_key<K: Ord, F: Fn(&T) -> K>(&mut self, key: F) where T: Debug;
+    fn after_dedup_equals<E: Borrow<T>>(&mut self, expected: &[E])
+        where T: PartialEq + Clone + Debug;
+    fn partition_and_assert<F, G>(&mut self, predicate: F, assertions: G)
+        where T: Clone,
+              F: Fn(&T) -> bool,
+              G: FnOnce(Spec<Vec<T>>, Spec<Vec<T>>);
+}
+
+pub trait VecResultAssertions<T, E>
+    where T: Debug,
+          E: Debug
+{
+    fn all_ok(&mut self);
+    fn all_ok_and_assert<F>(&mut self, assertions: F)
+        where T: Clone,
+              F: FnOnce(Spec<Vec<T>>);
+}
+
+pub trait VecOptionAssertions<T>
+    where T: Debug
+{
+    fn all_some(&mut self);
+    fn all_some_and_assert<F>(&mut self, assertions: F)
+        where T: Clone,
+              F: FnOnce(Spec<Vec<T>>);
+}
+
+pub trait NestedVecAssertions<T> {
+    fn flatten_and_assert<F>(&mut self, assertions: F)
+        where T: Clone,
+              F: FnOnce(Spec<Vec<T>>);
+}
+
+pub trait ByteAssertions {
+    fn is_valid_utf8_string(&mut self);
+    fn is_valid_utf8_string_and_assert<F>(&mut self, assertions: F)
+        where F: for<'a> FnOnce(Spec<'a, &'a str>);
+}
+
+pub trait FloatVecAssertions {
+    fn contains_no_nan_or_infinite(&mut self);
+    fn sums_to_one(&mut self, tolerance: f64);
+    fn is_valid_probability_distribution(&mut self, tolerance: f64);
+    fn is_elementwise_close_to<E: Borrow<[f64]>>(&mut self, expected: E, tolerance: f64);
+    fn has_no_outliers_by_iqr(&mut self, factor: f64);
+}
+
+pub trait IntVecAssertions {
+    fn bins_are_close_to<E: Borrow<[i64]>>(&mut self, expected: E, tolerance: i64);
+}
+
+pub trait StringVecAssertions {
+    fn all_share_prefix(&mut self, prefix: &str);
+    fn none_share_prefix(&mut self, prefix: &str);
+    fn is_sorted_ignoring_case(&mut self);
+}
+
+pub trait VecTupleAssertions<'s, K, V>
+    where K: PartialEq + Debug,
+          V: PartialEq + Debug
+{
+    fn contains_pair<KB: Borrow<K>, VB: Borrow<V>>(&mut self, key: KB, value: VB);
+    #[allow(clippy::wrong_self_convention)]
+    fn into_map_and_assert<F>(&mut self, assertions: F)
+        where K: Hash + Eq + Clone,
+              V: Clone,
+              F: FnOnce(Spec<HashMap<K, V>>);
+    fn key_value_pair_at(&mut self, index: usize) -> (Spec<'s, K>, Spec<'s, V>);
 }
 
-impl<'s, T> VecAssertions for Spec<'s, Vec<T>> {
+impl<'s, T: 's> VecAssertions<'s, T> for Spec<'s, Vec<T>> {
     /// Asserts that the length of the subject vector is equal to the provided length. The subject
     /// type must be of `Vec`.
     ///
@@ -22,6 +148,23 @@ impl<'s, T> VecAssertions for Spec<'s, Vec<T>> {
         }
     }
 
+    /// Asserts that the length of the subject vector satisfies the provided predicate. The subject
+    /// type must be of `Vec`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 2, 3, 4]).has_length_matching(|length| length % 2 == 0);
+    /// ```
+    fn has_length_matching<F: Fn(usize) -> bool>(&mut self, predicate: F) {
+        let length = self.subject.len();
+
+        if !predicate(length) {
+            AssertionFailure::from_spec(self)
+                .with_expected("vec to have length matching predicate".to_string())
+                .with_actual(format!("<{}>", length))
+                .fail();
+        }
+    }
+
     /// Asserts that the subject vector is empty. The subject type must be of `Vec`.
     ///
     /// ```rust,ignore
@@ -38,37 +181,2320 @@ impl<'s, T> VecAssertions for Spec<'s, Vec<T>> {
                 .fail();
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
+    /// Groups the elements of the subject vector by the key returned from the provided function,
+    /// then calls the provided assertions once per group with a `Spec` over that group. Groups
+    /// are visited in the order their keys first appear in the subject.
+    ///
+    /// The subject name of each group `Spec` will include the key of the group.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![1, 2, 3, 4, 5, 6];
+    /// assert_that(&test_vec).group_by_and_assert(|x| x % 2 == 0, |mut group| group.has_length(3));
+    /// ```
+    fn group_by_and_assert<K, F, G>(&mut self, key_fn: F, assertions: G)
+        where T: Clone,
+              K: Hash + Eq + Debug,
+              F: Fn(&T) -> K,
+              G: Fn(Spec<Vec<T>>)
+    {
+        let mut group_indexes: HashMap<K, usize> = HashMap::new();
+        let mut groups: Vec<(String, Vec<T>)> = vec![];
 
-    use super::super::prelude::*;
+        for element in self.subject {
+            let key = key_fn(element);
 
-    #[test]
-    fn should_not_panic_if_vec_length_matches_expected() {
-        let test_vec = vec![1, 2, 3];
-        assert_that(&test_vec).has_length(3);
+            if let Some(index) = group_indexes.get(&key) {
+                groups[*index].1.push(element.clone());
+                continue;
+            }
+
+            let group_name = match self.subject_name {
+                Some(name) => format!("{} group <{:?}>", name, key),
+                None => format!("group <{:?}>", key),
+            };
+
+            group_indexes.insert(key, groups.len());
+            groups.push((group_name, vec![element.clone()]));
+        }
+
+        for (group_name, group) in &groups {
+            assertions(Spec {
+                subject: group,
+                subject_name: Some(group_name),
+                location: self.location.clone(),
+                description: self.description,
+            });
+        }
     }
 
-    #[test]
-    #[should_panic(expected = "\n\texpected: vec to have length <1>\n\t but was: <3>")]
-    fn should_panic_if_vec_length_does_not_match_expected() {
-        let test_vec = vec![1, 2, 3];
-        assert_that(&test_vec).has_length(1);
+    /// Asserts that the subject vector is not empty, then returns a new `Spec` containing its
+    /// maximum element. The contained type must implement `Ord`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 3, 2]).max_element().is_equal_to(3);
+    /// ```
+    fn max_element(&mut self) -> Spec<'s, T>
+        where T: Ord + Debug
+    {
+        match self.subject.iter().max() {
+            Some(element) => {
+                Spec {
+                    subject: element,
+                    subject_name: self.subject_name,
+                    location: self.location.clone(),
+                    description: self.description,
+                }
+            }
+            None => {
+                fail_for_empty_vec(self);
+                unreachable!();
+            }
+        }
     }
 
-    #[test]
-    fn should_not_panic_if_vec_was_expected_to_be_empty_and_is() {
-        let test_vec: Vec<u8> = vec![];
-        assert_that(&test_vec).is_empty();
+    /// Asserts that the subject vector is not empty, then returns a new `Spec` containing its
+    /// minimum element. The contained type must implement `Ord`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 3, 2]).min_element().is_equal_to(1);
+    /// ```
+    fn min_element(&mut self) -> Spec<'s, T>
+        where T: Ord + Debug
+    {
+        match self.subject.iter().min() {
+            Some(element) => {
+                Spec {
+                    subject: element,
+                    subject_name: self.subject_name,
+                    location: self.location.clone(),
+                    description: self.description,
+                }
+            }
+            None => {
+                fail_for_empty_vec(self);
+                unreachable!();
+            }
+        }
     }
 
-    #[test]
-    #[should_panic(expected = "\n\texpected: an empty vec\
-                   \n\t but was: a vec with length <1>")]
-    fn should_panic_if_vec_was_expected_to_be_empty_and_is_not() {
-        assert_that(&vec![1]).is_empty();
+    /// Asserts that the sum of the elements of the subject vector is equal to the expected value.
+    /// The contained type must implement `Sum` and `PartialEq`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 2, 3]).sum_is(6);
+    /// ```
+    fn sum_is<E: Borrow<T>>(&mut self, expected: E)
+        where T: Sum<&'s T> + PartialEq + Debug
+    {
+        let borrowed_expected = expected.borrow();
+        let sum: T = self.subject.iter().sum();
+
+        if !sum.eq(borrowed_expected) {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("vec to have sum <{:?}>", borrowed_expected))
+                .with_actual(format!("<{:?}>", sum))
+                .fail();
+        }
+    }
+
+    /// Asserts that the product of the elements of the subject vector is equal to the expected
+    /// value. The contained type must implement `Product` and `PartialEq`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![2, 3, 4]).product_is(24);
+    /// ```
+    fn product_is<E: Borrow<T>>(&mut self, expected: E)
+        where T: Product<&'s T> + PartialEq + Debug
+    {
+        let borrowed_expected = expected.borrow();
+        let product: T = self.subject.iter().product();
+
+        if !product.eq(borrowed_expected) {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("vec to have product <{:?}>", borrowed_expected))
+                .with_actual(format!("<{:?}>", product))
+                .fail();
+        }
+    }
+
+    /// Asserts that each element of the subject vector is greater than the previous element by
+    /// at least the provided step. The contained type must implement `Sub` and `PartialOrd`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 3, 6]).increases_by_at_least(2);
+    /// ```
+    fn increases_by_at_least<E: Borrow<T>>(&mut self, step: E)
+        where T: Sub<Output = T> + PartialOrd + Copy + Debug
+    {
+        let borrowed_step = *step.borrow();
+
+        for (index, pair) in self.subject.windows(2).enumerate() {
+            let (previous, next) = (pair[0], pair[1]);
+
+            // Only subtract when the result can't go below zero, so unsigned types are supported.
+            if next < previous || next - previous < borrowed_step {
+                AssertionFailure::from_spec(self)
+                    .with_expected(format!("vec increasing by at least <{:?}>", borrowed_step))
+                    .with_actual(format!("<{:?}> followed by <{:?}> at index <{}>",
+                                         previous,
+                                         next,
+                                         index))
+                    .fail();
+            }
+        }
+    }
+
+    /// Asserts that the subject vector contains at least the provided number of consecutive
+    /// occurrences of the value. The contained type must implement `PartialEq`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 0, 0, 0, 1]).contains_run_of(0, 3);
+    /// ```
+    fn contains_run_of<E: Borrow<T>>(&mut self, value: E, length: usize)
+        where T: PartialEq + Debug
+    {
+        let borrowed_value = value.borrow();
+        let mut current_run = 0;
+        let mut longest_run = 0;
+
+        for element in self.subject {
+            if element.eq(borrowed_value) {
+                current_run += 1;
+                longest_run = longest_run.max(current_run);
+            } else {
+                current_run = 0;
+            }
+        }
+
+        if longest_run < length {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("vec containing a run of <{}> <{:?}>",
+                                       length,
+                                       borrowed_value))
+                .with_actual(format!("a longest run of <{}>", longest_run))
+                .fail();
+        }
+    }
+
+    /// Asserts that the subject vector has an element at the provided index, and that the element
+    /// satisfies the predicate. The description of the predicate is used in the failure message.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 2, 3]).has_element_at_satisfying(1, |x| x % 2 == 0, "even");
+    /// ```
+    fn has_element_at_satisfying<F>(&mut self, index: usize, predicate: F, description: &str)
+        where T: Debug,
+              F: Fn(&T) -> bool
+    {
+        let expected_message = format!("vec with element at index <{}> matching <{}>",
+                                       index,
+                                       description);
+
+        match self.subject.get(index) {
+            Some(element) if predicate(element) => {}
+            Some(element) => {
+                AssertionFailure::from_spec(self)
+                    .with_expected(expected_message)
+                    .with_actual(format!("<{:?}>", element))
+                    .fail();
+            }
+            None => {
+                AssertionFailure::from_spec(self)
+                    .with_expected(expected_message)
+                    .with_actual(format!("a vec of length <{}>", self.subject.len()))
+                    .fail();
+            }
+        }
+    }
+
+    /// Passes a `Spec` over the first N elements of the subject vector to the provided assertions.
+    /// Fails if the subject vector has fewer than N elements.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 2, 3]).first_n_and_assert(2, |mut first| first.has_length(2));
+    /// ```
+    fn first_n_and_assert<F>(&mut self, n: usize, assertions: F)
+        where T: Clone,
+              F: FnOnce(Spec<Vec<T>>)
+    {
+        check_has_at_least(self, n);
+
+        let elements = self.subject[..n].to_vec();
+        assertions(Spec {
+            subject: &elements,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
+
+    /// Passes a `Spec` over the last N elements of the subject vector to the provided assertions.
+    /// Fails if the subject vector has fewer than N elements.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 2, 3]).last_n_and_assert(2, |mut last| last.has_length(2));
+    /// ```
+    fn last_n_and_assert<F>(&mut self, n: usize, assertions: F)
+        where T: Clone,
+              F: FnOnce(Spec<Vec<T>>)
+    {
+        check_has_at_least(self, n);
+
+        let elements = self.subject[self.subject.len() - n..].to_vec();
+        assertions(Spec {
+            subject: &elements,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
+
+    /// Asserts that every element of the subject vector satisfies at least one of the provided
+    /// predicates. Predicates of different closure types can be passed boxed.
+    ///
+    /// ```rust,ignore
+    /// let predicates: Vec<Box<dyn Fn(&i32) -> bool>> = vec![Box::new(|x| x % 2 == 0),
+    ///                                                       Box::new(|x| *x > 10)];
+    /// assert_that(&vec![2, 11, 4]).each_matching_any_of(predicates);
+    /// ```
+    fn each_matching_any_of<F>(&mut self, predicates: Vec<F>)
+        where T: Debug,
+              F: Fn(&T) -> bool
+    {
+        let (indexes, elements): (Vec<usize>, Vec<&T>) = self.subject
+            .iter()
+            .enumerate()
+            .filter(|&(_, element)| !predicates.iter().any(|predicate| predicate(element)))
+            .unzip();
+
+        if !indexes.is_empty() {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("all elements to match at least one of <{}> predicates",
+                                       predicates.len()))
+                .with_actual(format!("<{:?}> at indexes <{:?}>", elements, indexes))
+                .fail();
+        }
+    }
+
+    /// Asserts that every pair of consecutive elements in the subject vector satisfies the
+    /// predicate. The description of the predicate is used in the failure message, along with
+    /// every violating pair.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 2, 4]).adjacent_pairs_satisfy(|a, b| a < b, "strictly increasing");
+    /// ```
+    fn adjacent_pairs_satisfy<F>(&mut self, predicate: F, description: &str)
+        where T: Debug,
+              F: Fn(&T, &T) -> bool
+    {
+        let violations: Vec<(usize, &[T])> = self.subject
+            .windows(2)
+            .enumerate()
+            .filter(|&(_, pair)| !predicate(&pair[0], &pair[1]))
+            .collect();
+
+        if !violations.is_empty() {
+            let pairs: Vec<(&T, &T)> =
+                violations.iter().map(|&(_, pair)| (&pair[0], &pair[1])).collect();
+            let indexes: Vec<(usize, usize)> =
+                violations.iter().map(|&(index, _)| (index, index + 1)).collect();
+
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("all adjacent pairs matching <{}>", description))
+                .with_actual(format!("<{:?}> at indexes <{:?}>", pairs, indexes))
+                .fail();
+        }
+    }
+
+    /// Asserts that exactly one element of the subject vector satisfies the predicate, then
+    /// returns a new `Spec` over that element.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 2, 3]).has_exactly_one_satisfying(|x| x % 2 == 0).is_equal_to(2);
+    /// ```
+    fn has_exactly_one_satisfying<F>(&mut self, predicate: F) -> Spec<'s, T>
+        where T: Debug,
+              F: Fn(&T) -> bool
+    {
+        let subject: &'s Vec<T> = self.subject;
+        let matching: Vec<&'s T> = subject.iter().filter(|element| predicate(element)).collect();
+
+        if matching.len() != 1 {
+            let actual_message = if matching.is_empty() {
+                "no matching elements".to_string()
+            } else {
+                format!("<{}> matching elements <{:?}>", matching.len(), matching)
+            };
+
+            AssertionFailure::from_spec(self)
+                .with_expected("exactly one element matching predicate".to_string())
+                .with_actual(actual_message)
+                .fail();
+        }
+
+        Spec {
+            subject: matching[0],
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        }
+    }
+
+    /// Asserts that every dependency of each element, as returned by `deps`, appears earlier in
+    /// the subject vector. The first element with a dependency which appears later, or not at all,
+    /// is reported.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec!["core", "lib", "app"]).is_topologically_sorted_given(|crate_name| {
+    ///     match *crate_name {
+    ///         "app" => vec!["lib", "core"],
+    ///         "lib" => vec!["core"],
+    ///         _ => vec![],
+    ///     }
+    /// });
+    /// ```
+    fn is_topologically_sorted_given<F>(&mut self, deps: F)
+        where T: PartialEq + Debug,
+              F: Fn(&T) -> Vec<T>
+    {
+        for (index, element) in self.subject.iter().enumerate() {
+            for dependency in deps(element) {
+                let position = self.subject.iter().position(|candidate| *candidate == dependency);
+                let actual_message = match position {
+                    Some(position) if position < index => continue,
+                    Some(position) => {
+                        format!("<{:?}> at index <{}> depends on <{:?}> at index <{}>",
+                                element,
+                                index,
+                                dependency,
+                                position)
+                    }
+                    None => {
+                        format!("<{:?}> at index <{}> depends on missing <{:?}>",
+                                element,
+                                index,
+                                dependency)
+                    }
+                };
+
+                AssertionFailure::from_spec(self)
+                    .with_expected("dependencies of each element to appear before it".to_string())
+                    .with_actual(actual_message)
+                    .fail();
+            }
+        }
+    }
+
+    /// Asserts that the subject vector can be rotated to equal the expected elements. On failure
+    /// the rotation of the subject matching the most elements is reported.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![3, 1, 2]).is_rotation_of(&[1, 2, 3]);
+    /// ```
+    fn is_rotation_of<E: Borrow<T>>(&mut self, expected: &[E])
+        where T: PartialEq + Debug
+    {
+        let subject = self.subject;
+        let borrowed_expected: Vec<&T> = expected.iter().map(|element| element.borrow()).collect();
+        let length = subject.len();
+
+        let actual_message = if length != borrowed_expected.len() {
+            format!("<{:?}> of length <{}>", subject, length)
+        } else if length == 0 {
+            return;
+        } else {
+            // Count the matching positions for each rotation, keeping the first best match.
+            let (offset, matches) = (0..length)
+                .map(|offset| {
+                    let matches = borrowed_expected.iter()
+                        .enumerate()
+                        .filter(|&(index, element)| subject[(index + offset) % length] == **element)
+                        .count();
+                    (offset, matches)
+                })
+                .fold((0, 0), |best, current| if current.1 > best.1 { current } else { best });
+
+            if matches == length {
+                return;
+            }
+
+            let rotation: Vec<&T> = subject[offset..].iter().chain(&subject[..offset]).collect();
+            format!("<{:?}> (closest rotation <{:?}> matches <{}> of <{}> elements)",
+                    subject,
+                    rotation,
+                    matches,
+                    length)
+        };
+
+        AssertionFailure::from_spec(self)
+            .with_expected(format!("rotation of <{:?}>", borrowed_expected))
+            .with_actual(actual_message)
+            .fail();
+    }
+
+    /// Asserts that the subject vector is a prefix of the provided elements. On failure the
+    /// number of elements which matched before diverging is reported.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 2]).is_prefix_of(&[1, 2, 3]);
+    /// ```
+    fn is_prefix_of<E: Borrow<T>>(&mut self, full: &[E])
+        where T: PartialEq + Debug
+    {
+        let borrowed_full: Vec<&T> = full.iter().map(|element| element.borrow()).collect();
+        let matched = self.subject
+            .iter()
+            .zip(&borrowed_full)
+            .take_while(|&(element, full_element)| element == *full_element)
+            .count();
+
+        check_matched_affix(self, "prefix", &borrowed_full, matched);
+    }
+
+    /// Asserts that the subject vector is a suffix of the provided elements. On failure the
+    /// number of elements which matched, counting from the end, before diverging is reported.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![2, 3]).is_suffix_of(&[1, 2, 3]);
+    /// ```
+    fn is_suffix_of<E: Borrow<T>>(&mut self, full: &[E])
+        where T: PartialEq + Debug
+    {
+        let borrowed_full: Vec<&T> = full.iter().map(|element| element.borrow()).collect();
+        let matched = self.subject
+            .iter()
+            .rev()
+            .zip(borrowed_full.iter().rev())
+            .take_while(|&(element, full_element)| element == *full_element)
+            .count();
+
+        check_matched_affix(self, "suffix", &borrowed_full, matched);
+    }
+
+    /// Asserts that the elements of the subject vector appear in the provided elements in the
+    /// same order, though not necessarily next to each other. On failure the elements of the
+    /// subject which could not be found in order are reported.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 3]).is_subsequence_of(&[1, 2, 3]);
+    /// ```
+    fn is_subsequence_of<E: Borrow<T>>(&mut self, full: &[E])
+        where T: PartialEq + Debug
+    {
+        let borrowed_full: Vec<&T> = full.iter().map(|element| element.borrow()).collect();
+        let mut full_elements = borrowed_full.iter();
+        let matched = self.subject
+            .iter()
+            .take_while(|element| full_elements.any(|full_element| element == full_element))
+            .count();
+
+        if matched != self.subject.len() {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("subsequence of <{:?}>", borrowed_full))
+                .with_actual(format!("<{:?}> (<{:?}> not found in order from index <{}>)",
+                                     self.subject,
+                                     &self.subject[matched..],
+                                     matched))
+                .fail();
+        }
+    }
+
+    /// Pairs each element of the subject vector with the element at the same index of the other
+    /// vector, and passes `Spec`s over both to the provided assertions. Every pair is checked
+    /// before failing, and the failures for all pairs are reported together along with any
+    /// difference in length.
+    ///
+    /// ```rust,ignore
+    /// let requests = vec![1, 2, 3];
+    /// let responses = vec![2, 4, 6];
+    ///
+    /// assert_that(&requests).is_interleaved_with(&responses, |request, mut response| {
+    ///     response.is_equal_to(request.subject * 2);
+    /// });
+    /// ```
+    fn is_interleaved_with<U: Debug, F: Fn(Spec<T>, Spec<U>)>(&mut self, other: &'s [U], f: F) {
+        let mut problems = vec![];
+        let mut pair_failures = vec![];
+
+        if self.subject.len() != other.len() {
+            problems.push(format!("vec of length <{}> interleaved with <{}> elements",
+                                  self.subject.len(),
+                                  other.len()));
+        }
+
+        for (index, (element, other_element)) in self.subject.iter().zip(other).enumerate() {
+            // The pair failures are reported under this spec, so they don't repeat its name.
+            let failure = checked::capture_failure(|| {
+                f(assert_that(element), assert_that(other_element))
+            });
+
+            if let Some(message) = failure {
+                pair_failures.push((index, message.trim().to_string()));
+            }
+        }
+
+        if !pair_failures.is_empty() {
+            let indexes: Vec<usize> = pair_failures.iter().map(|&(index, _)| index).collect();
+            problems.push(format!("failing pairs at indexes <{:?}>", indexes));
+        }
+
+        if problems.is_empty() {
+            return;
+        }
+
+        let mut failure = AssertionFailure::from_spec(self);
+        failure.with_expected("all interleaved pairs to pass the assertions".to_string())
+            .with_actual(problems.join(", "));
+
+        for (index, message) in pair_failures {
+            failure.with_note(format!("pair at index <{}>: {}", index, message));
+        }
+
+        failure.fail();
+    }
+
+    /// Maps each element of the subject vector with the provided function, then passes a `Spec`
+    /// over the resulting vector to the provided assertions.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec!["a", "bb"]).map_elements_and_assert(|element| element.len(),
+    ///                                                       |mut lengths| {
+    ///     lengths.is_equal_to(vec![1, 2]);
+    /// });
+    /// ```
+    fn map_elements_and_assert<U, F, G>(&mut self, f: F, assertions: G)
+        where U: Debug,
+              F: Fn(&T) -> U,
+              G: FnOnce(Spec<Vec<U>>)
+    {
+        let mapped: Vec<U> = self.subject.iter().map(f).collect();
+
+        assertions(Spec {
+            subject: &mapped,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
+
+    /// Asserts that the subject vector contains elements matching each of the provided predicates
+    /// in order, although not necessarily next to each other. On failure the number of predicates
+    /// which were matched before the search failed is reported.
+    ///
+    /// ```rust,ignore
+    /// let predicates: Vec<Box<dyn Fn(&&str) -> bool>> =
+    ///     vec![Box::new(|event| event.starts_with("open")),
+    ///          Box::new(|event| event.starts_with("close"))];
+    ///
+    /// assert_that(&vec!["open file", "read", "close file"])
+    ///     .contains_subsequence_matching(predicates);
+    /// ```
+    fn contains_subsequence_matching<F>(&mut self, predicates: Vec<F>)
+        where T: Debug,
+              F: Fn(&T) -> bool
+    {
+        let mut elements = self.subject.iter();
+        let matched = predicates.iter()
+            .take_while(|predicate| elements.any(predicate))
+            .count();
+
+        if matched != predicates.len() {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("vec containing elements matching <{}> predicates in order",
+                                       predicates.len()))
+                .with_actual(format!("<{:?}> (only the first <{}> predicates were matched)",
+                                     self.subject,
+                                     matched))
+                .fail();
+        }
+    }
+
+    /// Asserts that the subject vector contains the expected number of distinct elements.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 2, 2, 3]).count_distinct_is(3);
+    /// ```
+    fn count_distinct_is(&mut self, expected: usize)
+        where T: Hash + Eq + Debug
+    {
+        let distinct = self.subject.iter().collect::<HashSet<&T>>().len();
+
+        if distinct != expected {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("vec with <{}> distinct elements", expected))
+                .with_actual(format!("<{}> distinct elements in <{:?}>", distinct, self.subject))
+                .fail();
+        }
+    }
+
+    /// Asserts that the subject vector is already in the order that a stable sort by the provided
+    /// key would give it, i.e. that the keys of its elements are in non-decreasing order. The
+    /// first pair of elements out of order is reported.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec!["b", "a", "cc"]).is_sorted_stably_by_key(|element| element.len());
+    /// ```
+    fn is_sorted_stably_by_key<K: Ord, F: Fn(&T) -> K>(&mut self, key: F)
+        where T: Debug
+    {
+        let unsorted_pair = self.subject
+            .windows(2)
+            .enumerate()
+            .find(|&(_, pair)| key(&pair[0]) > key(&pair[1]));
+
+        if let Some((index, pair)) = unsorted_pair {
+            AssertionFailure::from_spec(self)
+                .with_expected("vec sorted by key".to_string())
+                .with_actual(format!("<{:?}> at index <{}> before <{:?}>", pair[0], index, pair[1]))
+                .fail();
+        }
+    }
+
+    /// Asserts that the subject vector is equal to the expected elements once consecutive
+    /// repeated elements have been removed, as done by `Vec::dedup`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 1, 2, 2, 2, 1]).after_dedup_equals(&[1, 2, 1]);
+    /// ```
+    fn after_dedup_equals<E: Borrow<T>>(&mut self, expected: &[E])
+        where T: PartialEq + Clone + Debug
+    {
+        let mut deduped = self.subject.clone();
+        deduped.dedup();
+
+        let borrowed_expected: Vec<&T> = expected.iter().map(|element| element.borrow()).collect();
+
+        if !deduped.iter().eq(borrowed_expected.iter().cloned()) {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("vec equal to <{:?}> after dedup", borrowed_expected))
+                .with_actual(format!("<{:?}> deduplicated from <{:?}>", deduped, self.subject))
+                .fail();
+        }
+    }
+
+    /// Splits the subject vector into the elements which satisfy the provided predicate and those
+    /// which don't, keeping their order, then passes a `Spec` over each partition to the provided
+    /// assertions.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1, 2, 3, 4]).partition_and_assert(|value| value % 2 == 0,
+    ///                                                     |mut even, mut odd| {
+    ///     even.is_equal_to(vec![2, 4]);
+    ///     odd.has_length(2);
+    /// });
+    /// ```
+    fn partition_and_assert<F, G>(&mut self, predicate: F, assertions: G)
+        where T: Clone,
+              F: Fn(&T) -> bool,
+              G: FnOnce(Spec<Vec<T>>, Spec<Vec<T>>)
+    {
+        let (matching, non_matching): (Vec<T>, Vec<T>) =
+            self.subject.iter().cloned().partition(|element| predicate(element));
+
+        let matching_spec = Spec {
+            subject: &matching,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        };
+        let non_matching_spec = Spec {
+            subject: &non_matching,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        };
+
+        assertions(matching_spec, non_matching_spec);
+    }
+}
+
+impl<'s, T, E> VecResultAssertions<T, E> for Spec<'s, Vec<Result<T, E>>>
+    where T: Debug,
+          E: Debug
+{
+    /// Asserts that every element of the subject vector is `Ok`. The subject type must be a
+    /// `Vec` of `Result`.
+    ///
+    /// ```rust,ignore
+    /// let test_vec: Vec<Result<u8, &str>> = vec![Ok(1), Ok(2)];
+    /// assert_that(&test_vec).all_ok();
+    /// ```
+    fn all_ok(&mut self) {
+        check_all_ok(self);
+    }
+
+    /// Asserts that every element of the subject vector is `Ok`, then passes a `Spec` over the
+    /// unwrapped values to the provided assertions. The subject type must be a `Vec` of `Result`.
+    ///
+    /// ```rust,ignore
+    /// let test_vec: Vec<Result<u8, &str>> = vec![Ok(1), Ok(2)];
+    /// assert_that(&test_vec).all_ok_and_assert(|mut values| values.is_equal_to(vec![1, 2]));
+    /// ```
+    fn all_ok_and_assert<F>(&mut self, assertions: F)
+        where T: Clone,
+              F: FnOnce(Spec<Vec<T>>)
+    {
+        check_all_ok(self);
+
+        let values: Vec<T> = self.subject
+            .iter()
+            .filter_map(|result| result.as_ref().ok().cloned())
+            .collect();
+        assertions(Spec {
+            subject: &values,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
+}
+
+fn check_all_ok<T: Debug, E: Debug>(spec: &Spec<Vec<Result<T, E>>>) {
+    let errors: Vec<&E> = spec.subject.iter().filter_map(|result| result.as_ref().err()).collect();
+
+    if !errors.is_empty() {
+        AssertionFailure::from_spec(spec)
+            .with_expected("all elements to be result[ok]".to_string())
+            .with_actual(format!("result[error] elements <{:?}>", errors))
+            .fail();
+    }
+}
+
+impl<'s, T> VecOptionAssertions<T> for Spec<'s, Vec<Option<T>>>
+    where T: Debug
+{
+    /// Asserts that every element of the subject vector is `Some`. The subject type must be a
+    /// `Vec` of `Option`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![Some(1), Some(2)]).all_some();
+    /// ```
+    fn all_some(&mut self) {
+        check_all_some(self);
+    }
+
+    /// Asserts that every element of the subject vector is `Some`, then passes a `Spec` over the
+    /// unwrapped values to the provided assertions. The subject type must be a `Vec` of `Option`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![Some(1), Some(2)])
+    ///     .all_some_and_assert(|mut values| values.is_equal_to(vec![1, 2]));
+    /// ```
+    fn all_some_and_assert<F>(&mut self, assertions: F)
+        where T: Clone,
+              F: FnOnce(Spec<Vec<T>>)
+    {
+        check_all_some(self);
+
+        let values: Vec<T> = self.subject.iter().filter_map(|option| option.clone()).collect();
+        assertions(Spec {
+            subject: &values,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
+}
+
+fn check_all_some<T: Debug>(spec: &Spec<Vec<Option<T>>>) {
+    let none_indexes: Vec<usize> = spec.subject
+        .iter()
+        .enumerate()
+        .filter(|&(_, option)| option.is_none())
+        .map(|(index, _)| index)
+        .collect();
+
+    if !none_indexes.is_empty() {
+        AssertionFailure::from_spec(spec)
+            .with_expected("all elements to be option[some]".to_string())
+            .with_actual(format!("option[none] at indexes <{:?}>", none_indexes))
+            .fail();
+    }
+}
+
+impl<'s, T> NestedVecAssertions<T> for Spec<'s, Vec<Vec<T>>> {
+    /// Flattens the subject vector by one level, then passes a `Spec` over the flattened values to
+    /// the provided assertions. The subject type must be a `Vec` of `Vec`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![vec![1, 2], vec![], vec![3]])
+    ///     .flatten_and_assert(|mut values| values.is_equal_to(vec![1, 2, 3]));
+    /// ```
+    fn flatten_and_assert<F>(&mut self, assertions: F)
+        where T: Clone,
+              F: FnOnce(Spec<Vec<T>>)
+    {
+        let values: Vec<T> = self.subject.iter().flat_map(|inner| inner.iter().cloned()).collect();
+        assertions(Spec {
+            subject: &values,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
+}
+
+impl<'s, K, V> VecTupleAssertions<'s, K, V> for Spec<'s, Vec<(K, V)>>
+    where K: PartialEq + Debug,
+          V: PartialEq + Debug
+{
+    /// Asserts that the subject vector contains a pair with the expected key and value, in any
+    /// position. The subject type must be a `Vec` of tuples.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![("host", "localhost"), ("port", "8080")];
+    /// assert_that(&test_vec).contains_pair("port", "8080");
+    /// ```
+    fn contains_pair<KB: Borrow<K>, VB: Borrow<V>>(&mut self, key: KB, value: VB) {
+        let borrowed_key = key.borrow();
+        let borrowed_value = value.borrow();
+
+        if self.subject.iter().any(|(k, v)| k.eq(borrowed_key) && v.eq(borrowed_value)) {
+            return;
+        }
+
+        let expected_message = format!("vec containing pair with key <{:?}> and value <{:?}>",
+                                       borrowed_key,
+                                       borrowed_value);
+
+        let key_values: Vec<&V> = self.subject
+            .iter()
+            .filter(|(k, _)| k.eq(borrowed_key))
+            .map(|(_, v)| v)
+            .collect();
+
+        if !key_values.is_empty() {
+            AssertionFailure::from_spec(self)
+                .with_expected(expected_message)
+                .with_actual(format!("key <{:?}> with values <{:?}> instead",
+                                     borrowed_key,
+                                     key_values))
+                .fail();
+
+            unreachable!();
+        }
+
+        let keys: Vec<&K> = self.subject.iter().map(|(k, _)| k).collect();
+
+        AssertionFailure::from_spec(self)
+            .with_expected(expected_message)
+            .with_actual(format!("no matching key, keys are <{:?}>", keys))
+            .fail();
+    }
+
+    /// Collects the pairs of the subject vector into a `HashMap`, then passes a `Spec` over it to
+    /// the provided assertions. When a key appears more than once, its last value is kept.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![("host", "localhost"), ("port", "8080")];
+    /// assert_that(&test_vec).into_map_and_assert(|mut map| map.contains_entry("port", "8080"));
+    /// ```
+    fn into_map_and_assert<F>(&mut self, assertions: F)
+        where K: Hash + Eq + Clone,
+              V: Clone,
+              F: FnOnce(Spec<HashMap<K, V>>)
+    {
+        let map: HashMap<K, V> = self.subject.iter().cloned().collect();
+
+        assertions(Spec {
+            subject: &map,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
+
+    /// Returns a pair of new `Spec`s over the key and the value of the pair at the provided index
+    /// of the subject vector. Fails if there is no pair at that index.
+    ///
+    /// ```rust,ignore
+    /// let test_vec = vec![("host", "localhost"), ("port", "8080")];
+    /// let (mut key, mut value) = assert_that(&test_vec).key_value_pair_at(1);
+    /// key.is_equal_to("port");
+    /// value.is_equal_to("8080");
+    /// ```
+    fn key_value_pair_at(&mut self, index: usize) -> (Spec<'s, K>, Spec<'s, V>) {
+        let subject: &'s Vec<(K, V)> = self.subject;
+
+        match subject.get(index) {
+            Some((key, value)) => {
+                let key_spec = Spec {
+                    subject: key,
+                    subject_name: self.subject_name,
+                    location: self.location.clone(),
+                    description: self.description,
+                };
+                let value_spec = Spec {
+                    subject: value,
+                    subject_name: self.subject_name,
+                    location: self.location.clone(),
+                    description: self.description,
+                };
+
+                (key_spec, value_spec)
+            }
+            None => {
+                AssertionFailure::from_spec(self)
+                    .with_expected(format!("vec with pair at index <{}>", index))
+                    .with_actual(format!("a vec of length <{}>", subject.len()))
+                    .fail();
+
+                unreachable!();
+            }
+        }
+    }
+}
+
+impl<'s> ByteAssertions for Spec<'s, Vec<u8>> {
+    /// Asserts that the subject bytes are valid UTF-8. The subject type must be a `Vec<u8>`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&b"Hello".to_vec()).is_valid_utf8_string();
+    /// ```
+    fn is_valid_utf8_string(&mut self) {
+        let subject = self.subject;
+        check_valid_utf8(self, subject);
+    }
+
+    /// Asserts that the subject bytes are valid UTF-8, then passes a `Spec` over the decoded
+    /// `&str` to the provided assertions. The subject type must be a `Vec<u8>`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&b"Hello".to_vec())
+    ///     .is_valid_utf8_string_and_assert(|mut string| string.starts_with("He"));
+    /// ```
+    fn is_valid_utf8_string_and_assert<F>(&mut self, assertions: F)
+        where F: for<'a> FnOnce(Spec<'a, &'a str>)
+    {
+        let subject = self.subject;
+        let string = check_valid_utf8(self, subject);
+
+        assertions(Spec {
+            subject: &string,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
+}
+
+impl<'s> ByteAssertions for Spec<'s, &'s [u8]> {
+    /// Asserts that the subject bytes are valid UTF-8. The subject type must be a `&[u8]`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&&b"Hello"[..]).is_valid_utf8_string();
+    /// ```
+    fn is_valid_utf8_string(&mut self) {
+        let subject = self.subject;
+        check_valid_utf8(self, subject);
+    }
+
+    /// Asserts that the subject bytes are valid UTF-8, then passes a `Spec` over the decoded
+    /// `&str` to the provided assertions. The subject type must be a `&[u8]`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&&b"Hello"[..])
+    ///     .is_valid_utf8_string_and_assert(|mut string| string.starts_with("He"));
+    /// ```
+    fn is_valid_utf8_string_and_assert<F>(&mut self, assertions: F)
+        where F: for<'a> FnOnce(Spec<'a, &'a str>)
+    {
+        let subject = self.subject;
+        let string = check_valid_utf8(self, subject);
+
+        assertions(Spec {
+            subject: &string,
+            subject_name: self.subject_name,
+            location: self.location.clone(),
+            description: self.description,
+        });
+    }
+}
+
+impl<'s> FloatVecAssertions for Spec<'s, Vec<f64>> {
+    /// Asserts that none of the elements of the subject vector are `NaN` or infinite. The subject
+    /// type must be a `Vec<f64>`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![0.5, -1.0]).contains_no_nan_or_infinite();
+    /// ```
+    fn contains_no_nan_or_infinite(&mut self) {
+        let (indexes, values): (Vec<usize>, Vec<f64>) = self.subject
+            .iter()
+            .enumerate()
+            .filter(|&(_, value)| !value.is_finite())
+            .unzip();
+
+        if !indexes.is_empty() {
+            AssertionFailure::from_spec(self)
+                .with_expected("all elements to be finite".to_string())
+                .with_actual(format!("<{:?}> at indexes <{:?}>", values, indexes))
+                .fail();
+        }
+    }
+
+    /// Asserts that the elements of the subject vector sum to one within the specified tolerance.
+    /// The subject type must be a `Vec<f64>`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![0.25, 0.75]).sums_to_one(0.001);
+    /// ```
+    fn sums_to_one(&mut self, tolerance: f64) {
+        let sum: f64 = self.subject.iter().sum();
+
+        if !is_one_within(sum, tolerance) {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("elements summing to <1.0> (tolerance of <{:?}>)",
+                                       tolerance))
+                .with_actual(format!("<{:?}> summing to <{:?}>", self.subject, sum))
+                .fail();
+        }
+    }
+
+    /// Asserts that the subject vector is a probability distribution: every element is between
+    /// zero and one, and the elements sum to one within the specified tolerance. The subject type
+    /// must be a `Vec<f64>`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![0.5, 0.25, 0.25]).is_valid_probability_distribution(0.001);
+    /// ```
+    fn is_valid_probability_distribution(&mut self, tolerance: f64) {
+        let invalid_element = self.subject
+            .iter()
+            .enumerate()
+            .find(|&(_, element)| !(0.0..=1.0).contains(element));
+        let sum: f64 = self.subject.iter().sum();
+
+        let actual_message = match invalid_element {
+            Some((index, element)) if *element < 0.0 => {
+                format!("negative element <{:?}> at index <{}>", element, index)
+            }
+            Some((index, element)) => {
+                format!("element <{:?}> outside of [0, 1] at index <{}>", element, index)
+            }
+            None if !is_one_within(sum, tolerance) => {
+                format!("<{:?}> summing to <{:?}>", self.subject, sum)
+            }
+            None => return,
+        };
+
+        AssertionFailure::from_spec(self)
+            .with_expected(format!("a probability distribution (tolerance of <{:?}>)", tolerance))
+            .with_actual(actual_message)
+            .fail();
+    }
+
+    /// Asserts that the subject vector has the same length as the expected elements, and that
+    /// each element is close to the expected element at the same index by the specified
+    /// tolerance. On failure the first differing element is reported along with the maximum
+    /// difference across all elements, to help with choosing a tolerance.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1.0, 2.01]).is_elementwise_close_to(vec![1.0, 2.0], 0.1);
+    /// ```
+    fn is_elementwise_close_to<E: Borrow<[f64]>>(&mut self, expected: E, tolerance: f64) {
+        let subject = self.subject;
+        let borrowed_expected = expected.borrow();
+        let expected_message = format!("vec elementwise close to <{:?}> (tolerance of <{:?}>)",
+                                       borrowed_expected,
+                                       tolerance);
+
+        if subject.len() != borrowed_expected.len() {
+            AssertionFailure::from_spec(self)
+                .with_expected(expected_message)
+                .with_actual(format!("<{:?}> of length <{}>", subject, subject.len()))
+                .fail();
+            return;
+        }
+
+        let differences: Vec<f64> = subject.iter()
+            .zip(borrowed_expected)
+            .map(|(element, expected_element)| (element - expected_element).abs())
+            .collect();
+
+        let first_difference = differences.iter()
+            .position(|difference| difference.is_nan() || *difference > tolerance.abs());
+
+        if let Some(index) = first_difference {
+            // NaN is kept as the maximum so that it isn't hidden by larger finite differences.
+            let max_difference = differences.iter()
+                .fold(0.0, |max, &difference| {
+                    if difference.is_nan() || difference > max {
+                        difference
+                    } else {
+                        max
+                    }
+                });
+
+            AssertionFailure::from_spec(self)
+                .with_expected(expected_message)
+                .with_actual(format!("<{:?}> at index <{}> (maximum difference of <{:?}>)",
+                                     subject[index],
+                                     index,
+                                     max_difference))
+                .fail();
+        }
+    }
+
+    /// Asserts that none of the elements of the subject vector are outliers, i.e. further than
+    /// `factor` times the interquartile range below the first quartile or above the third
+    /// quartile. The quartiles are interpolated from the elements which aren't `NaN`, and at least
+    /// four such elements are needed. `NaN` elements are reported as outliers.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![1.0, 2.0, 3.0, 4.0, 5.0]).has_no_outliers_by_iqr(1.5);
+    /// ```
+    fn has_no_outliers_by_iqr(&mut self, factor: f64) {
+        let subject = self.subject;
+        let expected_message = format!("no outliers beyond <{:?}> times the interquartile range",
+                                       factor);
+
+        let mut sorted: Vec<f64> = subject.iter()
+            .cloned()
+            .filter(|value| !value.is_nan())
+            .collect();
+
+        if sorted.len() < 4 {
+            AssertionFailure::from_spec(self)
+                .with_expected(expected_message)
+                .with_actual(format!("<{:?}> with fewer than <4> elements to compute quartiles",
+                                     subject))
+                .fail();
+            return;
+        }
+
+        sorted.sort_by(|first, second| first.partial_cmp(second).unwrap());
+
+        let first_quartile = interpolate_quantile(&sorted, 0.25);
+        let third_quartile = interpolate_quantile(&sorted, 0.75);
+        let margin = factor.abs() * (third_quartile - first_quartile);
+        let (lower_fence, upper_fence) = (first_quartile - margin, third_quartile + margin);
+
+        let outliers: Vec<f64> = subject.iter()
+            .cloned()
+            .filter(|value| !(*value >= lower_fence && *value <= upper_fence))
+            .collect();
+
+        if !outliers.is_empty() {
+            AssertionFailure::from_spec(self)
+                .with_expected(expected_message)
+                .with_actual(format!("outliers <{:?}> outside of <[{:?}, {:?}]>",
+                                     outliers,
+                                     lower_fence,
+                                     upper_fence))
+                .fail();
+        }
+    }
+}
+
+impl<'s> IntVecAssertions for Spec<'s, Vec<i64>> {
+    /// Asserts that the subject vector of histogram bin counts has the same length as the
+    /// expected bins, and that each count is within the specified absolute tolerance of the
+    /// expected count. Every bin outside the tolerance is reported with its deviation.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec![10, 21, 9]).bins_are_close_to(vec![10, 20, 10], 2);
+    /// ```
+    fn bins_are_close_to<E: Borrow<[i64]>>(&mut self, expected: E, tolerance: i64) {
+        let subject = self.subject;
+        let borrowed_expected = expected.borrow();
+        let expected_message = format!("bins close to <{:?}> (tolerance of <{}>)",
+                                       borrowed_expected,
+                                       tolerance);
+
+        if subject.len() != borrowed_expected.len() {
+            AssertionFailure::from_spec(self)
+                .with_expected(expected_message)
+                .with_actual(format!("<{:?}> of length <{}>", subject, subject.len()))
+                .fail();
+            return;
+        }
+
+        // Widen before subtracting so that counts at opposite ends of the range can't overflow.
+        let deviations: Vec<String> = subject.iter()
+            .zip(borrowed_expected)
+            .map(|(&count, &expected_count)| i128::from(count) - i128::from(expected_count))
+            .enumerate()
+            .filter(|&(_, deviation)| deviation.abs() > i128::from(tolerance).abs())
+            .map(|(index, deviation)| format!("bin <{}> off by <{:+}>", index, deviation))
+            .collect();
+
+        if !deviations.is_empty() {
+            AssertionFailure::from_spec(self)
+                .with_expected(expected_message)
+                .with_actual(format!("<{:?}> with {}", subject, deviations.join(", ")))
+                .fail();
+        }
+    }
+}
+
+impl<'s> StringVecAssertions for Spec<'s, Vec<String>> {
+    /// Asserts that every element of the subject vector starts with the given prefix. The subject
+    /// type must be a `Vec<String>`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec!["app_user".to_owned(), "app_order".to_owned()]).all_share_prefix("app_");
+    /// ```
+    fn all_share_prefix(&mut self, prefix: &str) {
+        let (indexes, elements) = find_elements_by_prefix(self.subject, prefix, false);
+
+        if !indexes.is_empty() {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("all elements to start with <{:?}>", prefix))
+                .with_actual(format!("<{:?}> at indexes <{:?}>", elements, indexes))
+                .fail();
+        }
+    }
+
+    /// Asserts that no element of the subject vector starts with the given prefix. The subject
+    /// type must be a `Vec<String>`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec!["app_user".to_owned(), "app_order".to_owned()]).none_share_prefix("tmp_");
+    /// ```
+    fn none_share_prefix(&mut self, prefix: &str) {
+        let (indexes, elements) = find_elements_by_prefix(self.subject, prefix, true);
+
+        if !indexes.is_empty() {
+            AssertionFailure::from_spec(self)
+                .with_expected(format!("no elements to start with <{:?}>", prefix))
+                .with_actual(format!("<{:?}> at indexes <{:?}>", elements, indexes))
+                .fail();
+        }
+    }
+
+    /// Asserts that the elements of the subject vector are in non-decreasing order when ASCII
+    /// case is ignored. The first pair of elements out of order is reported. The subject type
+    /// must be a `Vec<String>`.
+    ///
+    /// ```rust,ignore
+    /// assert_that(&vec!["apple".to_owned(), "Banana".to_owned()]).is_sorted_ignoring_case();
+    /// ```
+    fn is_sorted_ignoring_case(&mut self) {
+        let unsorted_pair = self.subject
+            .windows(2)
+            .enumerate()
+            .find(|&(_, pair)| pair[0].to_ascii_lowercase() > pair[1].to_ascii_lowercase());
+
+        if let Some((index, pair)) = unsorted_pair {
+            AssertionFailure::from_spec(self)
+                .with_expected("vec sorted ignoring case".to_string())
+                .with_actual(format!("<{:?}> at index <{}> before <{:?}>", pair[0], index, pair[1]))
+                .fail();
+        }
+    }
+}
+
+fn find_elements_by_prefix<'a>(subject: &'a [String],
+                               prefix: &str,
+                               starting_with_prefix: bool)
+                               -> (Vec<usize>, Vec<&'a String>) {
+    subject.iter()
+        .enumerate()
+        .filter(|&(_, element)| element.starts_with(prefix) == starting_with_prefix)
+        .unzip()
+}
+
+fn check_matched_affix<T: Debug>(spec: &Spec<Vec<T>>, affix: &str, full: &[&T], matched: usize) {
+    let length = spec.subject.len();
+
+    if matched != length {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("{} of <{:?}>", affix, full))
+            .with_actual(format!("<{:?}> (<{}> of <{}> elements matched before diverging)",
+                                 spec.subject,
+                                 matched,
+                                 length))
+            .fail();
+    }
+}
+
+fn is_one_within(value: f64, tolerance: f64) -> bool {
+    (value - 1.0).abs() <= tolerance.abs()
+}
+
+/// Returns the quantile at the given fraction of the sorted values, interpolating linearly
+/// between the two closest values.
+fn interpolate_quantile(sorted: &[f64], fraction: f64) -> f64 {
+    let position = fraction * (sorted.len() - 1) as f64;
+    let lower_index = position.floor() as usize;
+    let upper_index = position.ceil() as usize;
+
+    let weight = position - lower_index as f64;
+
+    sorted[lower_index] + (sorted[upper_index] - sorted[lower_index]) * weight
+}
+
+fn check_valid_utf8<'a, 's, S: DescriptiveSpec<'s>>(spec: &'s S, subject: &'a [u8]) -> &'a str {
+    match str::from_utf8(subject) {
+        Ok(string) => string,
+        Err(error) => {
+            let offset = error.valid_up_to();
+            // An unknown error length means the bytes end part way through a character.
+            let end = error.error_len().map_or(subject.len(), |length| offset + length);
+            let invalid_bytes = subject[offset..end]
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect::<Vec<String>>()
+                .join(" ");
+
+            AssertionFailure::from_spec(spec)
+                .with_expected("valid UTF-8 bytes".to_string())
+                .with_actual(format!("invalid bytes <{}> at byte offset <{}>",
+                                     invalid_bytes,
+                                     offset))
+                .fail();
+
+            unreachable!();
+        }
+    }
+}
+
+fn check_has_at_least<T>(spec: &Spec<Vec<T>>, n: usize) {
+    if spec.subject.len() < n {
+        AssertionFailure::from_spec(spec)
+            .with_expected(format!("vec with at least <{}> elements", n))
+            .with_actual(format!("a vec of length <{}>", spec.subject.len()))
+            .fail();
+    }
+}
+
+fn fail_for_empty_vec<T>(spec: &Spec<Vec<T>>) {
+    AssertionFailure::from_spec(spec)
+        .with_expected("a non-empty vec".to_string())
+        .with_actual("an empty vec".to_string())
+        .fail();
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::prelude::*;
+
+    type Predicate<T> = Box<dyn Fn(&T) -> bool>;
+
+    #[test]
+    fn should_not_panic_if_vec_length_matches_expected() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec).has_length(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec to have length <1>\n\t but was: <3>")]
+    fn should_panic_if_vec_length_does_not_match_expected() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec).has_length(1);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_was_expected_to_be_empty_and_is() {
+        let test_vec: Vec<u8> = vec![];
+        assert_that(&test_vec).is_empty();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: an empty vec\
+                   \n\t but was: a vec with length <1>")]
+    fn should_panic_if_vec_was_expected_to_be_empty_and_is_not() {
+        assert_that(&vec![1]).is_empty();
+    }
+
+    #[test]
+    fn should_return_max_element_of_vec() {
+        let test_vec = vec![1, 3, 2];
+        assert_that(&test_vec).max_element().is_equal_to(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a non-empty vec\n\t but was: an empty vec")]
+    fn should_panic_if_max_element_of_empty_vec_is_expected() {
+        let test_vec: Vec<u8> = vec![];
+        assert_that(&test_vec).max_element();
+    }
+
+    #[test]
+    fn should_return_min_element_of_vec() {
+        let test_vec = vec![2, 1, 3];
+        assert_that(&test_vec).min_element().is_equal_to(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a non-empty vec\n\t but was: an empty vec")]
+    fn should_panic_if_min_element_of_empty_vec_is_expected() {
+        let test_vec: Vec<u8> = vec![];
+        assert_that(&test_vec).min_element();
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_sum_matches_expected() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec).sum_is(6);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec to have sum <7>\n\t but was: <6>")]
+    fn should_panic_if_vec_sum_does_not_match_expected() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec).sum_is(7);
+    }
+
+    #[test]
+    fn should_not_panic_if_empty_vec_sum_is_zero() {
+        let test_vec: Vec<u8> = vec![];
+        assert_that(&test_vec).sum_is(0);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_product_matches_expected() {
+        let test_vec = vec![2, 3, 4];
+        assert_that(&test_vec).product_is(24);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec to have product <25>\n\t but was: <24>")]
+    fn should_panic_if_vec_product_does_not_match_expected() {
+        let test_vec = vec![2, 3, 4];
+        assert_that(&test_vec).product_is(25);
+    }
+
+    #[test]
+    fn should_not_panic_if_empty_vec_product_is_one() {
+        let test_vec: Vec<u8> = vec![];
+        assert_that(&test_vec).product_is(1);
+    }
+
+    #[test]
+    fn should_not_panic_if_every_group_satisfies_assertions() {
+        let test_vec = vec![1, 2, 3, 4, 5, 6];
+        assert_that(&test_vec).group_by_and_assert(|x| x % 2 == 0, |mut group| group.has_length(3));
+    }
+
+    #[test]
+    fn should_visit_groups_in_order_of_first_appearance() {
+        let test_vec = vec!["b1", "a1", "b2", "a2", "c1"];
+        let visited = ::std::cell::RefCell::new(vec![]);
+
+        assert_that(&test_vec).group_by_and_assert(|x| x.chars().next(), |group| {
+            visited.borrow_mut().push(group.subject.clone());
+        });
+
+        assert_that(&visited.into_inner())
+            .is_equal_to(vec![vec!["b1", "b2"], vec!["a1", "a2"], vec!["c1"]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [group <true>]\
+                   \n\texpected: vec to have length <3>\n\t but was: <2>")]
+    fn should_panic_if_a_group_does_not_satisfy_assertions() {
+        let test_vec = vec![1, 2, 3, 4, 5];
+        assert_that(&test_vec).group_by_and_assert(|x| x % 2 == 0, |mut group| group.has_length(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [numbers group <true>]")]
+    fn should_include_subject_name_in_group_panic() {
+        let test_vec = vec![1, 2, 3, 4, 5];
+        assert_that(&test_vec)
+            .named("numbers")
+            .group_by_and_assert(|x| x % 2 == 0, |mut group| group.has_length(3));
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_results_are_all_ok() {
+        let test_vec: Vec<Result<u8, &str>> = vec![Ok(1), Ok(2)];
+        assert_that(&test_vec).all_ok();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: all elements to be result[ok]\
+                   \n\t but was: result[error] elements <[\"Oh no\", \"Whoops\"]>")]
+    fn should_panic_if_vec_results_are_not_all_ok() {
+        let test_vec: Vec<Result<u8, &str>> = vec![Ok(1), Err("Oh no"), Ok(2), Err("Whoops")];
+        assert_that(&test_vec).all_ok();
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_ok_values_of_vec_results() {
+        let test_vec: Vec<Result<u8, &str>> = vec![Ok(1), Ok(2)];
+        assert_that(&test_vec).all_ok_and_assert(|mut values| values.is_equal_to(vec![1, 2]));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: all elements to be result[ok]\
+                   \n\t but was: result[error] elements <[\"Oh no\"]>")]
+    fn should_panic_before_asserting_on_ok_values_if_vec_results_are_not_all_ok() {
+        let test_vec: Vec<Result<u8, &str>> = vec![Ok(1), Err("Oh no")];
+        assert_that(&test_vec).all_ok_and_assert(|mut values| values.has_length(1));
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_options_are_all_some() {
+        let test_vec = vec![Some(1), Some(2)];
+        assert_that(&test_vec).all_some();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: all elements to be option[some]\
+                   \n\t but was: option[none] at indexes <[1, 3]>")]
+    fn should_panic_if_vec_options_are_not_all_some() {
+        let test_vec = vec![Some(1), None, Some(2), None];
+        assert_that(&test_vec).all_some();
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_some_values_of_vec_options() {
+        let test_vec = vec![Some(1), Some(2)];
+        assert_that(&test_vec).all_some_and_assert(|mut values| values.is_equal_to(vec![1, 2]));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: all elements to be option[some]\
+                   \n\t but was: option[none] at indexes <[0]>")]
+    fn should_panic_before_asserting_on_some_values_if_vec_options_are_not_all_some() {
+        let test_vec: Vec<Option<u8>> = vec![None, Some(1)];
+        assert_that(&test_vec).all_some_and_assert(|mut values| values.has_length(1));
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_flattened_nested_vec() {
+        let test_vec = vec![vec![1, 2], vec![], vec![3]];
+        assert_that(&test_vec).flatten_and_assert(|mut values| values.is_equal_to(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_flattened_empty_nested_vec() {
+        let test_vec: Vec<Vec<u8>> = vec![vec![], vec![]];
+        assert_that(&test_vec).flatten_and_assert(|mut values| values.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec to have length <4>\n\t but was: <3>")]
+    fn should_panic_if_flattened_nested_vec_does_not_match() {
+        let test_vec = vec![vec![1, 2], vec![3]];
+        assert_that(&test_vec).flatten_and_assert(|mut values| values.has_length(4));
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_contains_pair() {
+        let test_vec = vec![("host", "localhost"), ("port", "8080")];
+        assert_that(&test_vec).contains_pair("port", "8080");
+    }
+
+    #[test]
+    fn contains_pair_should_allow_owned_and_borrowed_forms() {
+        let key = "port".to_string();
+        let value = 8080;
+        let test_vec = vec![(key.clone(), value)];
+        assert_that(&test_vec).contains_pair(&key, value);
+        assert_that(&test_vec).contains_pair(key, value);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec containing pair with key <\"port\"> and value \
+                   <\"80\">\
+                   \n\t but was: key <\"port\"> with values <[\"8080\", \"8443\"]> instead")]
+    fn should_panic_if_vec_contains_key_with_different_values() {
+        let test_vec = vec![("port", "8080"), ("host", "localhost"), ("port", "8443")];
+        assert_that(&test_vec).contains_pair("port", "80");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec containing pair with key <\"user\"> and value \
+                   <\"admin\">\
+                   \n\t but was: no matching key, keys are <[\"host\", \"port\"]>")]
+    fn should_panic_if_vec_does_not_contain_key() {
+        let test_vec = vec![("host", "localhost"), ("port", "8080")];
+        assert_that(&test_vec).named("config").contains_pair("user", "admin");
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_vec_of_pairs_as_map() {
+        let test_vec = vec![("host", "localhost"), ("port", "8080"), ("port", "8443")];
+
+        assert_that(&test_vec).into_map_and_assert(|mut map| {
+            map.has_length(2);
+            map.contains_entry("port", "8443");
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [config]\
+                   \n\texpected: hashmap to contain key <\"user\">")]
+    fn should_panic_with_subject_name_if_vec_of_pairs_as_map_assertion_fails() {
+        let test_vec = vec![("host", "localhost")];
+
+        assert_that(&test_vec).named("config").into_map_and_assert(|mut map| {
+            map.contains_key("user");
+        });
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_key_value_pair_at_index() {
+        let test_vec = vec![("host", "localhost"), ("port", "8080")];
+
+        let (mut key, mut value) = assert_that(&test_vec).key_value_pair_at(1);
+        key.is_equal_to("port");
+        value.is_equal_to("8080");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec with pair at index <2>\
+                   \n\t but was: a vec of length <2>")]
+    fn should_panic_if_vec_has_no_key_value_pair_at_index() {
+        let test_vec = vec![("host", "localhost"), ("port", "8080")];
+
+        assert_that(&test_vec).key_value_pair_at(2);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_increases_by_at_least_step() {
+        let test_vec: Vec<u32> = vec![1, 3, 6, 8];
+        assert_that(&test_vec).increases_by_at_least(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [timestamps]\
+                   \n\texpected: vec increasing by at least <2>\
+                   \n\t but was: <3> followed by <4> at index <1>")]
+    fn should_panic_if_vec_does_not_increase_by_at_least_step() {
+        let test_vec: Vec<u32> = vec![1, 3, 4, 8];
+        assert_that(&test_vec).named("timestamps").increases_by_at_least(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec increasing by at least <2>\
+                   \n\t but was: <3> followed by <1> at index <0>")]
+    fn should_panic_if_vec_decreases_when_expected_to_increase_by_at_least_step() {
+        let test_vec: Vec<u32> = vec![3, 1];
+        assert_that(&test_vec).increases_by_at_least(2);
+    }
+
+    #[test]
+    fn should_not_panic_if_single_element_vec_increases_by_at_least_step() {
+        let test_vec = vec![1.5];
+        assert_that(&test_vec).increases_by_at_least(0.5);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_bytes_are_valid_utf8() {
+        assert_that(&"héllo".as_bytes().to_vec()).is_valid_utf8_string();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: valid UTF-8 bytes\
+                   \n\t but was: invalid bytes <ff> at byte offset <2>")]
+    fn should_panic_if_vec_bytes_are_not_valid_utf8() {
+        assert_that(&vec![b'h', b'i', 0xff, b'!']).is_valid_utf8_string();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: valid UTF-8 bytes\
+                   \n\t but was: invalid bytes <c3> at byte offset <1>")]
+    fn should_panic_if_slice_bytes_end_part_way_through_a_char() {
+        let bytes = "héllo".as_bytes();
+        assert_that(&&bytes[..2]).is_valid_utf8_string();
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_string_of_valid_utf8_bytes() {
+        assert_that(&b"Hello World".to_vec()).is_valid_utf8_string_and_assert(|mut string| {
+            string.starts_with("Hello");
+            string.ends_with("World");
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: string starting with <\"World\">\
+                   \n\t but was: <\"Hello\">")]
+    fn should_panic_if_string_of_valid_utf8_slice_bytes_does_not_match() {
+        let bytes: &[u8] = b"Hello";
+        assert_that(&bytes)
+            .is_valid_utf8_string_and_assert(|mut string| string.starts_with("World"));
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_contains_run_of_value() {
+        let test_vec = vec![1, 0, 0, 0, 1];
+        assert_that(&test_vec).contains_run_of(0, 3);
+        assert_that(&test_vec).contains_run_of(1, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec containing a run of <4> <0>\
+                   \n\t but was: a longest run of <3>")]
+    fn should_panic_if_vec_does_not_contain_long_enough_run_of_value() {
+        let test_vec = vec![0, 0, 1, 0, 0, 0];
+        assert_that(&test_vec).contains_run_of(0, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec containing a run of <3> <'a'>\
+                   \n\t but was: a longest run of <2>")]
+    fn should_panic_if_run_of_value_is_split_by_other_elements() {
+        let test_vec = vec!['a', 'a', 'b', 'a', 'a'];
+        assert_that(&test_vec).contains_run_of('a', 3);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_has_element_at_index_satisfying_predicate() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec).has_element_at_satisfying(1, |x| x % 2 == 0, "even");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec with element at index <2> matching <even>\
+                   \n\t but was: <3>")]
+    fn should_panic_if_vec_element_at_index_does_not_satisfy_predicate() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec).has_element_at_satisfying(2, |x| x % 2 == 0, "even");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec with element at index <3> matching <even>\
+                   \n\t but was: a vec of length <3>")]
+    fn should_panic_if_vec_has_no_element_at_index_to_satisfy_predicate() {
+        let test_vec = vec![1, 2, 3];
+        assert_that(&test_vec).has_element_at_satisfying(3, |x| x % 2 == 0, "even");
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_first_n_elements_of_vec() {
+        let test_vec = vec![1, 2, 3, 4];
+        assert_that(&test_vec).first_n_and_assert(3, |mut first| first.is_equal_to(vec![1, 2, 3]));
+        assert_that(&test_vec).first_n_and_assert(0, |mut first| first.is_empty());
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_last_n_elements_of_vec() {
+        let test_vec = vec![1, 2, 3, 4];
+        assert_that(&test_vec).last_n_and_assert(2, |mut last| last.is_equal_to(vec![3, 4]));
+        assert_that(&test_vec).last_n_and_assert(4, |mut last| last.is_equal_to(vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec with at least <3> elements\
+                   \n\t but was: a vec of length <2>")]
+    fn should_panic_if_vec_has_fewer_than_first_n_elements() {
+        let test_vec = vec![1, 2];
+        assert_that(&test_vec).first_n_and_assert(3, |_| {});
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: <[2, 3]>\n\t but was: <[3, 4]>")]
+    fn should_panic_if_last_n_elements_of_vec_do_not_match() {
+        let test_vec = vec![1, 2, 3, 4];
+        assert_that(&test_vec).last_n_and_assert(2, |mut last| last.is_equal_to(vec![2, 3]));
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_contains_no_nan_or_infinite() {
+        assert_that(&vec![0.5, -1.0, 0.0, f64::MAX]).contains_no_nan_or_infinite();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: all elements to be finite\
+                   \n\t but was: <[NaN]> at indexes <[2]>")]
+    fn should_panic_if_vec_contains_nan() {
+        assert_that(&vec![0.5, -1.0, f64::NAN]).contains_no_nan_or_infinite();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: all elements to be finite\
+                   \n\t but was: <[inf, -inf]> at indexes <[0, 3]>")]
+    fn should_panic_if_vec_contains_infinity() {
+        let test_vec = vec![f64::INFINITY, 0.5, -1.0, f64::NEG_INFINITY];
+        assert_that(&test_vec).contains_no_nan_or_infinite();
+    }
+
+    #[test]
+    fn should_not_panic_if_all_elements_share_prefix() {
+        assert_that(&vec!["app_user".to_owned(), "app_order".to_owned()]).all_share_prefix("app_");
+    }
+
+    #[test]
+    fn should_not_panic_if_empty_vec_is_expected_to_share_prefix() {
+        assert_that(&Vec::<String>::new()).all_share_prefix("app_");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [table names]\
+                   \n\texpected: all elements to start with <\"app_\">\
+                   \n\t but was: <[\"user\"]> at indexes <[1]>")]
+    fn should_panic_if_not_all_elements_share_prefix() {
+        assert_that(&vec!["app_order".to_owned(), "user".to_owned()])
+            .named("table names")
+            .all_share_prefix("app_");
+    }
+
+    #[test]
+    fn should_not_panic_if_no_elements_share_prefix() {
+        assert_that(&vec!["app_user".to_owned(), "app_order".to_owned()]).none_share_prefix("tmp_");
+        assert_that(&Vec::<String>::new()).none_share_prefix("tmp_");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: no elements to start with <\"tmp_\">\
+                   \n\t but was: <[\"tmp_user\", \"tmp_order\"]> at indexes <[0, 2]>")]
+    fn should_panic_if_some_elements_share_prefix() {
+        assert_that(&vec!["tmp_user".to_owned(), "app_user".to_owned(), "tmp_order".to_owned()])
+            .none_share_prefix("tmp_");
+    }
+
+    #[test]
+    fn should_not_panic_if_each_element_matches_any_predicate() {
+        let predicates: Vec<Predicate<i32>> = vec![Box::new(|x| x % 2 == 0),
+                                                   Box::new(|x| *x > 10)];
+        assert_that(&vec![2, 11, 4, 13]).each_matching_any_of(predicates);
+    }
+
+    #[test]
+    fn should_not_panic_if_empty_vec_is_expected_to_match_any_predicate() {
+        assert_that(&Vec::<i32>::new()).each_matching_any_of(vec![|x: &i32| *x > 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [identifiers]\
+                   \n\texpected: all elements to match at least one of <2> predicates\
+                   \n\t but was: <[\"\", \"3x\"]> at indexes <[1, 3]>")]
+    fn should_panic_if_element_matches_no_predicate() {
+        let is_uuid_like = |id: &&str| id.len() == 36;
+        let is_numeric = |id: &&str| !id.is_empty() && id.chars().all(|c| c.is_ascii_digit());
+        let predicates: Vec<&dyn Fn(&&str) -> bool> = vec![&is_uuid_like, &is_numeric];
+
+        assert_that(&vec!["42", "", "123e4567-e89b-12d3-a456-426614174000", "3x"])
+            .named("identifiers")
+            .each_matching_any_of(predicates);
+    }
+
+    #[test]
+    fn should_not_panic_if_adjacent_pairs_satisfy_predicate() {
+        assert_that(&vec![1, 2, 4]).adjacent_pairs_satisfy(|a, b| a < b, "strictly increasing");
+        assert_that(&vec![1]).adjacent_pairs_satisfy(|_, _| false, "never");
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [versions]\
+                   \n\texpected: all adjacent pairs matching <strictly increasing>\
+                   \n\t but was: <[(3, 3), (5, 2)]> at indexes <[(1, 2), (3, 4)]>")]
+    fn should_panic_if_adjacent_pairs_do_not_satisfy_predicate() {
+        assert_that(&vec![1, 3, 3, 5, 2])
+            .named("versions")
+            .adjacent_pairs_satisfy(|a, b| a < b, "strictly increasing");
+    }
+
+    #[test]
+    fn should_return_spec_on_only_element_satisfying_predicate() {
+        assert_that(&vec![1, 2, 3]).has_exactly_one_satisfying(|x| x % 2 == 0).is_equal_to(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: exactly one element matching predicate\
+                   \n\t but was: no matching elements")]
+    fn should_panic_if_no_element_satisfies_predicate() {
+        assert_that(&vec![1, 3, 5]).has_exactly_one_satisfying(|x| x % 2 == 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [numbers]\
+                   \n\texpected: exactly one element matching predicate\
+                   \n\t but was: <2> matching elements <[2, 4]>")]
+    fn should_panic_if_more_than_one_element_satisfies_predicate() {
+        assert_that(&vec![1, 2, 3, 4])
+            .named("numbers")
+            .has_exactly_one_satisfying(|x| x % 2 == 0);
+    }
+
+    fn crate_dependencies(crate_name: &&str) -> Vec<&'static str> {
+        match *crate_name {
+            "app" => vec!["lib", "core"],
+            "lib" => vec!["core"],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_is_topologically_sorted() {
+        assert_that(&vec!["core", "lib", "app"]).is_topologically_sorted_given(crate_dependencies);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [build order]\
+                   \n\texpected: dependencies of each element to appear before it\
+                   \n\t but was: <\"lib\"> at index <1> depends on <\"core\"> at index <2>")]
+    fn should_panic_if_dependency_appears_after_element() {
+        assert_that(&vec!["other", "lib", "core", "app"])
+            .named("build order")
+            .is_topologically_sorted_given(crate_dependencies);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: dependencies of each element to appear before it\
+                   \n\t but was: <\"lib\"> at index <0> depends on missing <\"core\">")]
+    fn should_panic_if_dependency_is_missing() {
+        assert_that(&vec!["lib", "app"]).is_topologically_sorted_given(crate_dependencies);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_sums_to_one() {
+        assert_that(&vec![0.25, 0.75]).sums_to_one(0.0);
+        assert_that(&vec![0.3, 0.3, 0.3]).sums_to_one(0.15);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: elements summing to <1.0> (tolerance of <0.01>)\
+                   \n\t but was: <[0.5, 0.25]> summing to <0.75>")]
+    fn should_panic_if_vec_does_not_sum_to_one() {
+        assert_that(&vec![0.5, 0.25]).sums_to_one(0.01);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_is_valid_probability_distribution() {
+        assert_that(&vec![0.5, 0.25, 0.25, 0.0]).is_valid_probability_distribution(0.001);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [softmax]\
+                   \n\texpected: a probability distribution (tolerance of <0.001>)\
+                   \n\t but was: <[0.5, 0.25]> summing to <0.75>")]
+    fn should_panic_if_probability_distribution_does_not_sum_to_one() {
+        assert_that(&vec![0.5, 0.25]).named("softmax").is_valid_probability_distribution(0.001);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a probability distribution (tolerance of <0.001>)\
+                   \n\t but was: negative element <-0.25> at index <1>")]
+    fn should_panic_if_probability_distribution_has_negative_element() {
+        assert_that(&vec![1.0, -0.25, 0.25]).is_valid_probability_distribution(0.001);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: a probability distribution (tolerance of <0.001>)\
+                   \n\t but was: element <1.5> outside of [0, 1] at index <0>")]
+    fn should_panic_if_probability_distribution_has_element_greater_than_one() {
+        assert_that(&vec![1.5, -0.5]).is_valid_probability_distribution(0.001);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_is_rotation_of_expected() {
+        assert_that(&vec![3, 1, 2]).is_rotation_of(&[1, 2, 3]);
+        assert_that(&vec![1, 2, 3]).is_rotation_of(&[1, 2, 3]);
+        assert_that(&Vec::<i32>::new()).is_rotation_of::<i32>(&[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [ring buffer]\
+                   \n\texpected: rotation of <[1, 2, 3, 4]>\
+                   \n\t but was: <[4, 1, 3, 2]> (closest rotation <[1, 3, 2, 4]> matches <2> of \
+                   <4> elements)")]
+    fn should_panic_if_vec_is_not_rotation_of_expected() {
+        assert_that(&vec![4, 1, 3, 2]).named("ring buffer").is_rotation_of(&[1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: rotation of <[1, 2, 3]>\
+                   \n\t but was: <[2, 1]> of length <2>")]
+    fn should_panic_if_vec_expected_to_be_rotation_has_different_length() {
+        assert_that(&vec![2, 1]).is_rotation_of(&[1, 2, 3]);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_length_matches_predicate() {
+        assert_that(&vec![1, 2, 3, 4]).has_length_matching(|length| length % 2 == 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec to have length matching predicate\
+                   \n\t but was: <3>")]
+    fn should_panic_if_vec_length_does_not_match_predicate() {
+        assert_that(&vec![1, 2, 3]).has_length_matching(|length| length % 2 == 0);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_is_prefix_of_expected() {
+        assert_that(&vec![1, 2]).is_prefix_of(&[1, 2, 3]);
+        assert_that(&vec![1, 2, 3]).is_prefix_of(&[1, 2, 3]);
+        assert_that(&Vec::<i32>::new()).is_prefix_of(&[1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [path]\
+                   \n\texpected: prefix of <[1, 2, 3]>\
+                   \n\t but was: <[1, 3]> (<1> of <2> elements matched before diverging)")]
+    fn should_panic_if_vec_is_not_prefix_of_expected() {
+        assert_that(&vec![1, 3]).named("path").is_prefix_of(&[1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: prefix of <[1, 2]>\
+                   \n\t but was: <[1, 2, 3]> (<2> of <3> elements matched before diverging)")]
+    fn should_panic_if_vec_expected_to_be_prefix_is_longer() {
+        assert_that(&vec![1, 2, 3]).is_prefix_of(&[1, 2]);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_is_suffix_of_expected() {
+        assert_that(&vec![2, 3]).is_suffix_of(&[1, 2, 3]);
+        assert_that(&Vec::<i32>::new()).is_suffix_of(&[1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: suffix of <[1, 2, 3]>\
+                   \n\t but was: <[1, 1, 3]> (<1> of <3> elements matched before diverging)")]
+    fn should_panic_if_vec_is_not_suffix_of_expected() {
+        assert_that(&vec![1, 1, 3]).is_suffix_of(&[1, 2, 3]);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_is_subsequence_of_expected() {
+        assert_that(&vec![1, 3, 5]).is_subsequence_of(&[1, 2, 3, 4, 5]);
+        assert_that(&vec![1, 2]).is_subsequence_of(&[1, 2]);
+        assert_that(&Vec::<i32>::new()).is_subsequence_of(&[1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [steps]\n\texpected: subsequence of <[1, 2, 3, 4]>\
+                   \n\t but was: <[1, 3, 2, 4]> (<[2, 4]> not found in order from index <2>)")]
+    fn should_panic_if_vec_is_not_subsequence_of_expected() {
+        assert_that(&vec![1, 3, 2, 4]).named("steps").is_subsequence_of(&[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn should_not_panic_if_interleaved_pairs_pass_assertions() {
+        let requests = vec![1, 2, 3];
+        let responses = vec![2, 4, 6];
+
+        assert_that(&requests).is_interleaved_with(&responses, |request, mut response| {
+            response.is_equal_to(request.subject * 2);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [requests]\
+                   \n\texpected: all interleaved pairs to pass the assertions\
+                   \n\t but was: failing pairs at indexes <[0, 2]>\
+                   \n\tnote: pair at index <0>: expected: <2>\n\t but was: <3>\
+                   \n\tnote: pair at index <2>: expected: <6>\n\t but was: <5>\n")]
+    fn should_panic_with_all_failing_interleaved_pairs() {
+        let requests = vec![1, 2, 3];
+        let responses = vec![3, 4, 5];
+
+        assert_that(&requests)
+            .named("requests")
+            .is_interleaved_with(&responses, |request, mut response| {
+                response.is_equal_to(request.subject * 2);
+            });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: all interleaved pairs to pass the assertions\
+                   \n\t but was: vec of length <2> interleaved with <3> elements\n")]
+    fn should_panic_if_interleaved_vecs_have_different_lengths() {
+        assert_that(&vec![1, 2]).is_interleaved_with(&[1, 2, 3], |_, _| {});
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_is_sorted_ignoring_case() {
+        assert_that(&vec!["apple".to_owned(), "Banana".to_owned(), "cherry".to_owned()])
+            .is_sorted_ignoring_case();
+        assert_that(&vec!["Apple".to_owned(), "apple".to_owned(), "APPLE".to_owned()])
+            .is_sorted_ignoring_case();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [menu]\n\texpected: vec sorted ignoring case\
+                   \n\t but was: <\"cherry\"> at index <1> before <\"Banana\">")]
+    fn should_panic_if_vec_is_not_sorted_ignoring_case() {
+        assert_that(&vec!["apple".to_owned(), "cherry".to_owned(), "Banana".to_owned()])
+            .named("menu")
+            .is_sorted_ignoring_case();
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec sorted ignoring case\
+                   \n\t but was: <\"Banana\"> at index <0> before <\"apple\">")]
+    fn should_panic_if_vec_is_only_sorted_case_sensitively() {
+        let test_vec = vec!["Banana".to_owned(), "apple".to_owned()];
+        assert_that(&(test_vec[0] < test_vec[1])).is_true();
+        assert_that(&test_vec).is_sorted_ignoring_case();
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_mapped_elements() {
+        assert_that(&vec!["a", "bb"]).map_elements_and_assert(|element| element.len(),
+                                                              |mut lengths| {
+            lengths.is_equal_to(vec![1, 2]);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [words]\
+                   \n\texpected: iterator to contain <3>\n\t but was: <[1, 2]>")]
+    fn should_panic_with_subject_name_if_mapped_elements_assertion_fails() {
+        assert_that(&vec!["a", "bb"])
+            .named("words")
+            .map_elements_and_assert(|element| element.len(), |mut lengths| lengths.contains(3));
+    }
+
+    fn event_predicates() -> Vec<Predicate<&'static str>> {
+        vec![Box::new(|event| event.starts_with("open")),
+             Box::new(|event| event.starts_with("write")),
+             Box::new(|event| event.starts_with("close"))]
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_contains_subsequence_matching_predicates() {
+        assert_that(&vec!["open a", "read a", "write a", "read a", "close a"])
+            .contains_subsequence_matching(event_predicates());
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_is_expected_to_contain_empty_subsequence() {
+        assert_that(&Vec::<&str>::new())
+            .contains_subsequence_matching(Vec::<Box<dyn Fn(&&str) -> bool>>::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [events]\
+                   \n\texpected: vec containing elements matching <3> predicates in order\
+                   \n\t but was: <[\"open a\", \"close a\", \"write a\"]> (only the first <2> \
+                   predicates were matched)")]
+    fn should_panic_if_vec_does_not_contain_subsequence_matching_predicates() {
+        assert_that(&vec!["open a", "close a", "write a"])
+            .named("events")
+            .contains_subsequence_matching(event_predicates());
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_has_expected_distinct_count() {
+        assert_that(&vec![1, 2, 2, 3]).count_distinct_is(3);
+        assert_that(&Vec::<i32>::new()).count_distinct_is(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [user ids]\
+                   \n\texpected: vec with <3> distinct elements\
+                   \n\t but was: <2> distinct elements in <[1, 1, 2]>")]
+    fn should_panic_if_vec_does_not_have_expected_distinct_count() {
+        assert_that(&vec![1, 1, 2]).named("user ids").count_distinct_is(3);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_is_elementwise_close_to_expected() {
+        assert_that(&vec![1.0, 2.01, -3.0]).is_elementwise_close_to(vec![1.0, 2.0, -2.95], 0.1);
+        assert_that(&vec![0.5]).is_elementwise_close_to(&[0.5][..], 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [signal]\
+                   \n\texpected: vec elementwise close to <[1.0, 2.0, 3.0]> (tolerance of <0.25>)\
+                   \n\t but was: <2.5> at index <1> (maximum difference of <1.0>)")]
+    fn should_panic_with_maximum_difference_if_vec_is_not_elementwise_close_to_expected() {
+        assert_that(&vec![1.0, 2.5, 4.0])
+            .named("signal")
+            .is_elementwise_close_to(vec![1.0, 2.0, 3.0], 0.25);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: vec elementwise close to <[1.0, 2.0]> (tolerance of \
+                   <0.25>)\n\t but was: <[1.0]> of length <1>")]
+    fn should_panic_if_vec_expected_to_be_elementwise_close_has_different_length() {
+        assert_that(&vec![1.0]).is_elementwise_close_to(vec![1.0, 2.0], 0.25);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_is_sorted_by_key() {
+        assert_that(&vec!["b", "a", "cc", "dd"]).is_sorted_stably_by_key(|element| element.len());
+        assert_that(&Vec::<&str>::new()).is_sorted_stably_by_key(|element| element.len());
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [responses]\n\texpected: vec sorted by key\
+                   \n\t but was: <(2, \"b\")> at index <1> before <(1, \"c\")>")]
+    fn should_panic_if_vec_is_not_sorted_by_key() {
+        assert_that(&vec![(1, "a"), (2, "b"), (1, "c")])
+            .named("responses")
+            .is_sorted_stably_by_key(|&(priority, _)| priority);
+    }
+
+    #[test]
+    fn should_not_panic_if_bins_are_within_tolerance() {
+        assert_that(&vec![10, 21, 9]).bins_are_close_to(vec![10, 20, 10], 2);
+        assert_that(&vec![5, 5]).bins_are_close_to(&[5, 5][..], 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [histogram]\
+                   \n\texpected: bins close to <[10, 20, 10]> (tolerance of <2>)\
+                   \n\t but was: <[10, 24, 7]> with bin <1> off by <+4>, bin <2> off by <-3>")]
+    fn should_panic_if_bins_deviate_beyond_tolerance() {
+        assert_that(&vec![10, 24, 7])
+            .named("histogram")
+            .bins_are_close_to(vec![10, 20, 10], 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: bins close to <[10, 20]> (tolerance of <2>)\
+                   \n\t but was: <[10]> of length <1>")]
+    fn should_panic_if_bins_have_different_length() {
+        assert_that(&vec![10]).bins_are_close_to(vec![10, 20], 2);
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_after_dedup_equals_expected() {
+        assert_that(&vec![1, 1, 2, 2, 2, 1]).after_dedup_equals(&[1, 2, 1]);
+        assert_that(&vec!["a", "b"]).after_dedup_equals(&["a", "b"]);
+        assert_that(&Vec::<i32>::new()).after_dedup_equals(&Vec::<i32>::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [signal]\
+                   \n\texpected: vec equal to <[1, 2]> after dedup\
+                   \n\t but was: <[1, 2, 1]> deduplicated from <[1, 1, 2, 1]>")]
+    fn should_panic_if_vec_after_dedup_does_not_equal_expected() {
+        assert_that(&vec![1, 1, 2, 1]).named("signal").after_dedup_equals(&[1, 2]);
+    }
+
+    #[test]
+    fn should_be_able_to_assert_on_partitions_of_vec() {
+        assert_that(&vec![1, 2, 3, 4, 5]).partition_and_assert(|value| value % 2 == 0,
+                                                               |mut even, mut odd| {
+            even.is_equal_to(vec![2, 4]);
+            odd.is_equal_to(vec![1, 3, 5]);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [users]\n\texpected: vec to have length <3>\
+                   \n\t but was: <2>")]
+    fn should_panic_with_subject_name_if_partition_assertion_fails() {
+        assert_that(&vec![true, false, true])
+            .named("users")
+            .partition_and_assert(|&active| active, |mut active, _| active.has_length(3));
+    }
+
+    #[test]
+    fn should_not_panic_if_vec_has_no_outliers_by_iqr() {
+        assert_that(&vec![3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0]).has_no_outliers_by_iqr(1.5);
+        assert_that(&vec![2.0, 2.0, 2.0, 2.0]).has_no_outliers_by_iqr(0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\tfor subject [latencies]\
+                   \n\texpected: no outliers beyond <1.5> times the interquartile range\
+                   \n\t but was: outliers <[100.0]> outside of <[-3.0, 13.0]>")]
+    fn should_panic_if_vec_has_outliers_by_iqr() {
+        assert_that(&vec![1.0, 2.0, 3.0, 4.0, 100.0, 5.0, 6.0, 7.0, 8.0])
+            .named("latencies")
+            .has_no_outliers_by_iqr(1.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "\n\texpected: no outliers beyond <1.5> times the interquartile range\
+                   \n\t but was: <[1.0, NaN, 2.0, 3.0]> with fewer than <4> elements to compute \
+                   quartiles")]
+    fn should_panic_if_vec_is_too_small_to_find_outliers_by_iqr() {
+        assert_that(&vec![1.0, f64::NAN, 2.0, 3.0]).has_no_outliers_by_iqr(1.5);
     }
 
 }